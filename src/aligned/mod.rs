@@ -1,3 +1,6 @@
+#[cfg(feature = "serde")]
+mod serde;
+
 use std::ops::Deref;
 
 use bytes::Bytes;
@@ -47,6 +50,20 @@ impl AlignedDataChunk {
     pub fn len(&self) -> usize {
         self.data.len()
     }
+
+    /// Returns mutable access to the data.
+    ///
+    /// Callers must call [`Self::rehash`] after modifying the data.
+    pub(crate) fn data_mut(&mut self) -> &mut [u8] {
+        &mut self.data
+    }
+
+    /// Recalculates the hash after [`Self::data_mut`] was used to modify the data.
+    pub(crate) fn rehash(&mut self) -> Result<Hash> {
+        self.hash = hash(&self.data)?;
+
+        Ok(self.hash)
+    }
 }
 
 impl AsRef<[u8]> for AlignedDataChunk {
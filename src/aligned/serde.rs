@@ -0,0 +1,49 @@
+use rkyv::util::AlignedVec;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{utils::serde_chunk, AlignedDataChunk};
+
+impl Serialize for AlignedDataChunk {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde_chunk::serialize(self, serializer)
+    }
+}
+
+/// Deserializes an [`AlignedDataChunk`], failing if its hash does not match its data.
+///
+/// The data is copied into an aligned buffer.
+impl<'de> Deserialize<'de> for AlignedDataChunk {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let (data, hash) = serde_chunk::deserialize(deserializer)?;
+        let mut aligned = AlignedVec::with_capacity(data.len());
+
+        aligned.extend_from_slice(&data);
+
+        Ok(Self::from_parts_unchecked(aligned, hash))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{AlignedDataChunk, DataChunk, OwnedDataChunk, Result};
+
+    #[test]
+    fn serde_roundtrip() -> Result<()> {
+        let chunk = AlignedDataChunk::try_from(&42_u64)?;
+
+        let json = serde_json::to_string(&chunk).map_err(std::io::Error::other)?;
+        let parsed: AlignedDataChunk =
+            serde_json::from_str(&json).map_err(std::io::Error::other)?;
+
+        assert_eq!(parsed.data_ref(), chunk.data_ref());
+        assert_eq!(parsed.hash(), chunk.hash());
+        assert_eq!(parsed.try_as::<u64>()?.to_native(), 42);
+
+        let other = OwnedDataChunk::from_data(b"other".to_vec())?;
+        let tampered = json.replace(&chunk.hash().to_string(), &other.hash().to_string());
+
+        assert!(serde_json::from_str::<AlignedDataChunk>(&tampered).is_err());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,7 @@
+use crate::{AnyDataChunk, DataChunk};
+
+impl AsRef<[u8]> for AnyDataChunk<'_> {
+    fn as_ref(&self) -> &[u8] {
+        self.data_ref()
+    }
+}
@@ -0,0 +1,63 @@
+use bytes::Bytes;
+use ps_hash::Hash;
+
+use crate::{
+    AnyDataChunk, BorrowedDataChunk, DataChunk, EncryptedDataChunk, OwnedDataChunk, Result,
+    SerializedDataChunk,
+};
+
+/// Evaluates `$expr` with `$chunk` bound to the chunk wrapped by `$value`.
+macro_rules! delegate {
+    ($value:expr, $chunk:ident => $expr:expr) => {
+        match $value {
+            AnyDataChunk::Aligned($chunk) => $expr,
+            AnyDataChunk::Borrowed($chunk) => $expr,
+            AnyDataChunk::BorrowedSerialized($chunk) => $expr,
+            AnyDataChunk::Encrypted($chunk) => $expr,
+            AnyDataChunk::Lazy($chunk) => $expr,
+            AnyDataChunk::Mbuf($chunk) => $expr,
+            #[cfg(feature = "mmap")]
+            AnyDataChunk::Mmap($chunk) => $expr,
+            AnyDataChunk::Owned($chunk) => $expr,
+            AnyDataChunk::Serialized($chunk) => $expr,
+        }
+    };
+}
+
+impl DataChunk for AnyDataChunk<'_> {
+    fn data_ref(&self) -> &[u8] {
+        delegate!(self, chunk => chunk.data_ref())
+    }
+
+    fn hash_ref(&self) -> &Hash {
+        delegate!(self, chunk => chunk.hash_ref())
+    }
+
+    fn verify(&self) -> Result<()> {
+        delegate!(self, chunk => chunk.verify())
+    }
+
+    fn encrypt(&self) -> Result<EncryptedDataChunk> {
+        delegate!(self, chunk => chunk.encrypt())
+    }
+
+    fn decrypt(&self, key: &Hash) -> Result<SerializedDataChunk> {
+        delegate!(self, chunk => DataChunk::decrypt(chunk, key))
+    }
+
+    fn borrow(&self) -> BorrowedDataChunk<'_> {
+        delegate!(self, chunk => chunk.borrow())
+    }
+
+    fn serialize(&self) -> Result<SerializedDataChunk> {
+        delegate!(self, chunk => chunk.serialize())
+    }
+
+    fn into_bytes(self) -> Bytes {
+        delegate!(self, chunk => chunk.into_bytes())
+    }
+
+    fn into_owned(self) -> OwnedDataChunk {
+        delegate!(self, chunk => chunk.into_owned())
+    }
+}
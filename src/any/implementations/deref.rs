@@ -0,0 +1,11 @@
+use std::ops::Deref;
+
+use crate::{AnyDataChunk, DataChunk};
+
+impl Deref for AnyDataChunk<'_> {
+    type Target = [u8];
+
+    fn deref(&self) -> &Self::Target {
+        self.data_ref()
+    }
+}
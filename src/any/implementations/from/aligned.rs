@@ -0,0 +1,7 @@
+use crate::{AlignedDataChunk, AnyDataChunk};
+
+impl From<AlignedDataChunk> for AnyDataChunk<'_> {
+    fn from(value: AlignedDataChunk) -> Self {
+        Self::Aligned(value)
+    }
+}
@@ -0,0 +1,7 @@
+use crate::{AnyDataChunk, BorrowedDataChunk};
+
+impl<'lt> From<BorrowedDataChunk<'lt>> for AnyDataChunk<'lt> {
+    fn from(value: BorrowedDataChunk<'lt>) -> Self {
+        Self::Borrowed(value)
+    }
+}
@@ -0,0 +1,7 @@
+use crate::{AnyDataChunk, BorrowedSerializedDataChunk};
+
+impl<'lt> From<BorrowedSerializedDataChunk<'lt>> for AnyDataChunk<'lt> {
+    fn from(value: BorrowedSerializedDataChunk<'lt>) -> Self {
+        Self::BorrowedSerialized(value)
+    }
+}
@@ -0,0 +1,11 @@
+use crate::{AnyDataChunk, CowDataChunk};
+
+impl<'lt> From<CowDataChunk<'lt>> for AnyDataChunk<'lt> {
+    fn from(value: CowDataChunk<'lt>) -> Self {
+        match value {
+            CowDataChunk::Borrowed(chunk) => Self::Borrowed(chunk),
+            CowDataChunk::Mbuf(chunk) => Self::Mbuf(chunk),
+            CowDataChunk::Owned(chunk) => Self::Owned(chunk),
+        }
+    }
+}
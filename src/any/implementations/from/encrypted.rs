@@ -0,0 +1,7 @@
+use crate::{AnyDataChunk, EncryptedDataChunk};
+
+impl From<EncryptedDataChunk> for AnyDataChunk<'_> {
+    fn from(value: EncryptedDataChunk) -> Self {
+        Self::Encrypted(value)
+    }
+}
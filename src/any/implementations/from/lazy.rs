@@ -0,0 +1,7 @@
+use crate::{AnyDataChunk, LazyDataChunk};
+
+impl From<LazyDataChunk> for AnyDataChunk<'_> {
+    fn from(value: LazyDataChunk) -> Self {
+        Self::Lazy(value)
+    }
+}
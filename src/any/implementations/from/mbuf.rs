@@ -0,0 +1,7 @@
+use crate::{AnyDataChunk, MbufDataChunk};
+
+impl<'lt> From<MbufDataChunk<'lt>> for AnyDataChunk<'lt> {
+    fn from(value: MbufDataChunk<'lt>) -> Self {
+        Self::Mbuf(value)
+    }
+}
@@ -0,0 +1,7 @@
+use crate::{AnyDataChunk, MmapDataChunk};
+
+impl From<MmapDataChunk> for AnyDataChunk<'_> {
+    fn from(value: MmapDataChunk) -> Self {
+        Self::Mmap(value)
+    }
+}
@@ -0,0 +1,11 @@
+mod aligned;
+mod borrowed;
+mod borrowed_serialized;
+mod cow;
+mod encrypted;
+mod lazy;
+mod mbuf;
+#[cfg(feature = "mmap")]
+mod mmap;
+mod owned;
+mod serialized;
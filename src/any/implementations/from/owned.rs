@@ -0,0 +1,7 @@
+use crate::{AnyDataChunk, OwnedDataChunk};
+
+impl From<OwnedDataChunk> for AnyDataChunk<'_> {
+    fn from(value: OwnedDataChunk) -> Self {
+        Self::Owned(value)
+    }
+}
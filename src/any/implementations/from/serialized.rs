@@ -0,0 +1,7 @@
+use crate::{AnyDataChunk, SerializedDataChunk};
+
+impl From<SerializedDataChunk> for AnyDataChunk<'_> {
+    fn from(value: SerializedDataChunk) -> Self {
+        Self::Serialized(value)
+    }
+}
@@ -0,0 +1,6 @@
+mod as_ref;
+mod datachunk;
+mod deref;
+mod from;
+#[cfg(feature = "serde")]
+mod serde;
@@ -0,0 +1,51 @@
+use serde::{Serialize, Serializer};
+
+#[cfg(feature = "mmap")]
+use crate::utils::serde_chunk;
+use crate::AnyDataChunk;
+
+/// Serializes the wrapped chunk exactly as it serializes itself.
+///
+/// [`crate::MmapDataChunk`] does not implement [`Serialize`],
+/// so it is serialized like an [`crate::OwnedDataChunk`].
+impl Serialize for AnyDataChunk<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::Aligned(chunk) => chunk.serialize(serializer),
+            Self::Borrowed(chunk) => chunk.serialize(serializer),
+            Self::BorrowedSerialized(chunk) => chunk.serialize(serializer),
+            Self::Encrypted(chunk) => chunk.serialize(serializer),
+            Self::Lazy(chunk) => chunk.serialize(serializer),
+            Self::Mbuf(chunk) => chunk.serialize(serializer),
+            #[cfg(feature = "mmap")]
+            Self::Mmap(chunk) => serde_chunk::serialize(chunk, serializer),
+            Self::Owned(chunk) => chunk.serialize(serializer),
+            Self::Serialized(chunk) => chunk.serialize(serializer),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{AnyDataChunk, OwnedDataChunk, Result, SerializedDataChunk};
+
+    #[test]
+    fn serializes_like_the_wrapped_chunk() -> Result<()> {
+        let serialized = SerializedDataChunk::from_data(b"hello world")?;
+        let owned = OwnedDataChunk::from_data(b"hello world".to_vec())?;
+
+        let expected = serde_json::to_string(&serialized).map_err(std::io::Error::other)?;
+        let any = serde_json::to_string(&AnyDataChunk::from(serialized))
+            .map_err(std::io::Error::other)?;
+
+        assert_eq!(any, expected);
+
+        let expected = serde_json::to_string(&owned).map_err(std::io::Error::other)?;
+        let any =
+            serde_json::to_string(&AnyDataChunk::from(owned)).map_err(std::io::Error::other)?;
+
+        assert_eq!(any, expected);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,89 @@
+mod implementations;
+
+#[cfg(feature = "mmap")]
+use crate::MmapDataChunk;
+use crate::{
+    AlignedDataChunk, BorrowedDataChunk, BorrowedSerializedDataChunk, EncryptedDataChunk,
+    LazyDataChunk, MbufDataChunk, OwnedDataChunk, SerializedDataChunk,
+};
+
+/// Any representation of a chunk of data.
+///
+/// Unlike [`crate::CowDataChunk`], this covers every chunk type in this crate,
+/// so APIs accepting "any chunk" need not copy into an [`OwnedDataChunk`].
+/// [`crate::DataChunk`] methods delegate to the wrapped chunk, so
+/// [`crate::DataChunk::into_bytes`] and [`crate::DataChunk::into_owned`] avoid
+/// copying wherever the wrapped type does.
+pub enum AnyDataChunk<'lt> {
+    Aligned(AlignedDataChunk),
+    Borrowed(BorrowedDataChunk<'lt>),
+    BorrowedSerialized(BorrowedSerializedDataChunk<'lt>),
+    Encrypted(EncryptedDataChunk),
+    Lazy(LazyDataChunk),
+    Mbuf(MbufDataChunk<'lt>),
+    #[cfg(feature = "mmap")]
+    Mmap(MmapDataChunk),
+    Owned(OwnedDataChunk),
+    Serialized(SerializedDataChunk),
+}
+
+#[cfg(test)]
+mod tests {
+    use bytes::Bytes;
+    use rkyv::util::AlignedVec;
+
+    use super::*;
+    use crate::{CowDataChunk, DataChunk, Result};
+
+    #[test]
+    fn every_variant_exposes_the_same_chunk() -> Result<()> {
+        let data = b"hello world";
+        let owned = OwnedDataChunk::from_data(data.to_vec())?;
+        let serialized = SerializedDataChunk::from_data(data)?;
+        let serialized_bytes = serialized.serialized_bytes().to_vec();
+        let mut aligned: AlignedVec = AlignedVec::new();
+        aligned.extend_from_slice(data);
+
+        let chunks: Vec<AnyDataChunk> = vec![
+            AlignedDataChunk::from_data_vec(aligned)?.into(),
+            BorrowedDataChunk::from_data(data)?.into(),
+            SerializedDataChunk::parse(&serialized_bytes)?.into(),
+            LazyDataChunk::from_data(data).into(),
+            owned.clone().into(),
+            serialized.into(),
+            CowDataChunk::from(owned.clone()).into(),
+        ];
+
+        for chunk in chunks {
+            assert_eq!(chunk.data_ref(), data);
+            assert_eq!(chunk.hash(), owned.hash());
+            let encrypted = chunk.encrypt()?;
+            let key = encrypted.chunk_key().to_hash()?;
+
+            assert_eq!(encrypted.decrypt()?.data_ref(), data);
+            assert_eq!(
+                AnyDataChunk::from(encrypted.into_owned())
+                    .decrypt(&key)?
+                    .data_ref(),
+                data
+            );
+            assert_eq!(chunk.into_owned(), owned);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn into_bytes_does_not_copy_owned_buffers() -> Result<()> {
+        let bytes = Bytes::from_static(b"hello world");
+        let chunk = AnyDataChunk::from(OwnedDataChunk::from_bytes(bytes.clone())?);
+
+        assert_eq!(chunk.into_bytes().as_ptr(), bytes.as_ptr());
+
+        let chunk = AnyDataChunk::from(LazyDataChunk::from_bytes(bytes.clone()));
+
+        assert_eq!(chunk.into_owned().data_ref().as_ptr(), bytes.as_ptr());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,316 @@
+//! A read-only file holding many [`Mbuf`](ps_mbuf::Mbuf) chunks, indexed by hash.
+//!
+//! # Layout
+//!
+//! | Offset          | Size           | Contents                                          |
+//! |-----------------|----------------|---------------------------------------------------|
+//! | `0`             | [`HEADER_SIZE`]| magic, version, byte order, `usize` width, reserved, record count, index offset |
+//! | [`HEADER_SIZE`] | variable       | records, each an `Mbuf<Hash, u8>` padded to [`MbufDataChunk::ALIGNMENT`] |
+//! | index offset    | `8 * count`    | record offsets (`u64`), sorted by record hash     |
+//!
+//! Header and index fields are little-endian. Records use the in-memory layout of
+//! [`Mbuf`](ps_mbuf::Mbuf), so arena files are only readable on the architecture
+//! that wrote them; the header records its byte order and `usize` width, and
+//! [`MbufArena::from_mmap`] rejects files written with different ones.
+
+use std::{
+    collections::HashSet,
+    fs::File,
+    io::{BufWriter, Seek, SeekFrom, Write},
+    path::Path,
+};
+
+use memmap2::Mmap;
+use ps_hash::Hash;
+use rkyv::util::AlignedVec;
+
+use crate::{DataChunk, DataChunkError, MbufDataChunk, Result};
+
+/// Magic bytes identifying an arena file.
+pub const ARENA_MAGIC: [u8; 4] = [0x89, b'P', b'D', b'A'];
+
+/// The arena format version written by [`MbufArena::create`].
+pub const ARENA_VERSION: u8 = 1;
+
+/// The byte order marker of the architecture writing or reading an arena.
+const BYTE_ORDER: u8 = if cfg!(target_endian = "little") { 0 } else { 1 };
+
+/// The `usize` width, in bytes, of the architecture writing or reading an arena.
+#[allow(clippy::cast_possible_truncation)]
+const USIZE_WIDTH: u8 = size_of::<usize>() as u8;
+
+/// Size of the arena header, in bytes.
+pub const HEADER_SIZE: usize = 32;
+
+const INDEX_ENTRY_SIZE: usize = size_of::<u64>();
+
+/// A memory-mapped arena file; see the [module documentation](self).
+#[derive(Debug)]
+pub struct MbufArena {
+    mmap: Mmap,
+    count: usize,
+    index_offset: usize,
+}
+
+impl MbufArena {
+    /// Writes `chunks` into a new arena file at `path`.
+    ///
+    /// Chunks with duplicate hashes are stored once. Returns the number of stored chunks.
+    pub fn create<P, I>(path: P, chunks: I) -> Result<usize>
+    where
+        P: AsRef<Path>,
+        I: IntoIterator,
+        I::Item: DataChunk,
+    {
+        let mut file = BufWriter::new(File::create(path)?);
+        let mut index: Vec<(Hash, u64)> = Vec::new();
+        let mut seen = HashSet::new();
+        let mut record: AlignedVec = AlignedVec::new();
+        let mut offset = HEADER_SIZE;
+
+        file.write_all(&[0; HEADER_SIZE])?;
+
+        for chunk in chunks {
+            if !seen.insert(chunk.hash()) {
+                continue;
+            }
+
+            let size = MbufDataChunk::required_size(chunk.data_ref().len())
+                .next_multiple_of(MbufDataChunk::ALIGNMENT);
+
+            record.clear();
+            record.resize(size, 0);
+
+            MbufDataChunk::write_into(&chunk, &mut record)?;
+            file.write_all(&record)?;
+
+            index.push((chunk.hash(), offset as u64));
+            offset += size;
+        }
+
+        index.sort_unstable_by_key(|(hash, _)| *hash);
+
+        for (_, record_offset) in &index {
+            file.write_all(&record_offset.to_le_bytes())?;
+        }
+
+        let mut header = [0u8; HEADER_SIZE];
+
+        header[..4].copy_from_slice(&ARENA_MAGIC);
+        header[4] = ARENA_VERSION;
+        header[5] = BYTE_ORDER;
+        header[6] = USIZE_WIDTH;
+        header[16..24].copy_from_slice(&(index.len() as u64).to_le_bytes());
+        header[24..32].copy_from_slice(&(offset as u64).to_le_bytes());
+
+        file.seek(SeekFrom::Start(0))?;
+        file.write_all(&header)?;
+
+        file.into_inner()
+            .map_err(std::io::IntoInnerError::into_error)?
+            .sync_all()?;
+
+        Ok(index.len())
+    }
+
+    /// Maps the arena file at `path` read-only and validates its header.
+    ///
+    /// # Safety
+    ///
+    /// The file must not be modified or truncated while the arena, or any chunk
+    /// borrowed from it, is alive.
+    pub unsafe fn open<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let file = File::open(path)?;
+
+        // SAFETY: upheld by the caller.
+        let mmap = unsafe { Mmap::map(&file)? };
+
+        Self::from_mmap(mmap)
+    }
+
+    /// Validates the header of an existing mapping.
+    ///
+    /// Fails with [`DataChunkError::InvalidLayout`] if the arena was written on an
+    /// architecture with a different byte order or `usize` width.
+    pub fn from_mmap(mmap: Mmap) -> Result<Self> {
+        if mmap.len() < HEADER_SIZE || mmap[..4] != ARENA_MAGIC {
+            return Err(DataChunkError::InvalidLayout);
+        }
+
+        if mmap[4] != ARENA_VERSION {
+            return Err(DataChunkError::UnsupportedVersion(mmap[4]));
+        }
+
+        if mmap[5] != BYTE_ORDER || mmap[6] != USIZE_WIDTH {
+            return Err(DataChunkError::InvalidLayout);
+        }
+
+        let count = read_offset(&mmap, 16)?;
+        let index_offset = read_offset(&mmap, 24)?;
+
+        let index_end = count
+            .checked_mul(INDEX_ENTRY_SIZE)
+            .and_then(|size| size.checked_add(index_offset))
+            .ok_or(DataChunkError::InvalidLayout)?;
+
+        if index_offset < HEADER_SIZE || index_end != mmap.len() {
+            return Err(DataChunkError::InvalidLayout);
+        }
+
+        Ok(Self {
+            mmap,
+            count,
+            index_offset,
+        })
+    }
+
+    /// Returns the number of chunks in this arena.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.count
+    }
+
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+
+    /// Looks up the chunk with hash `hash` in `O(log n)`, without copying.
+    pub fn get(&self, hash: &Hash) -> Result<Option<MbufDataChunk<'_>>> {
+        let (mut low, mut high) = (0, self.count);
+
+        while low < high {
+            let middle = low + (high - low) / 2;
+            let chunk = self.chunk_at(middle)?;
+
+            match chunk.hash_ref().cmp(hash) {
+                std::cmp::Ordering::Less => low = middle + 1,
+                std::cmp::Ordering::Greater => high = middle,
+                std::cmp::Ordering::Equal => return Ok(Some(chunk)),
+            }
+        }
+
+        Ok(None)
+    }
+
+    pub fn contains(&self, hash: &Hash) -> Result<bool> {
+        Ok(self.get(hash)?.is_some())
+    }
+
+    /// Iterates over all chunks, in hash order.
+    pub fn iter(&self) -> impl Iterator<Item = Result<MbufDataChunk<'_>>> {
+        (0..self.count).map(|position| self.chunk_at(position))
+    }
+
+    fn chunk_at(&self, position: usize) -> Result<MbufDataChunk<'_>> {
+        let offset = read_offset(&self.mmap, self.index_offset + position * INDEX_ENTRY_SIZE)?;
+
+        if offset >= self.index_offset {
+            return Err(DataChunkError::InvalidLayout);
+        }
+
+        MbufDataChunk::from_region(&self.mmap[offset..self.index_offset])
+    }
+}
+
+fn read_offset(bytes: &[u8], offset: usize) -> Result<usize> {
+    let field = bytes
+        .get(offset..offset + INDEX_ENTRY_SIZE)
+        .ok_or(DataChunkError::InvalidLayout)?;
+
+    usize::try_from(u64::from_le_bytes(field.try_into()?))
+        .map_err(|_| DataChunkError::InvalidLayout)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::*;
+    use crate::{test_utils::TempPath, CowDataChunk, OwnedDataChunk};
+
+    #[test]
+    fn arena_roundtrip() -> Result<()> {
+        let file = TempPath::new("arena-roundtrip");
+        let chunks = (0..100u8)
+            .map(|i| OwnedDataChunk::from_data(vec![i; usize::from(i)]))
+            .collect::<Result<Vec<_>>>()?;
+
+        let stored = MbufArena::create(&file.0, chunks.iter().chain(&chunks[..10]).cloned())?;
+
+        assert_eq!(stored, chunks.len());
+
+        // SAFETY: the file is private to this test and not modified.
+        let arena = unsafe { MbufArena::open(&file.0)? };
+
+        assert_eq!(arena.len(), chunks.len());
+
+        for chunk in &chunks {
+            let found = arena
+                .get(chunk.hash_ref())?
+                .ok_or(DataChunkError::NotFound)?;
+
+            assert_eq!(found.data_ref(), chunk.data_ref());
+            found.verify()?;
+
+            let cow = CowDataChunk::from(found);
+
+            assert!(matches!(cow, CowDataChunk::Mbuf(_)));
+        }
+
+        assert!(!arena.contains(&ps_hash::hash(b"missing")?)?);
+        assert_eq!(arena.iter().count(), chunks.len());
+
+        Ok(())
+    }
+
+    #[test]
+    fn arena_rejects_corrupt_header() -> Result<()> {
+        let file = TempPath::new("arena-corrupt");
+
+        MbufArena::create(
+            &file.0,
+            [OwnedDataChunk::from_data(b"hello world".to_vec())?],
+        )?;
+
+        let mut bytes = fs::read(&file.0)?;
+        bytes.pop();
+        fs::write(&file.0, &bytes)?;
+
+        // SAFETY: the file is private to this test and not modified.
+        let result = unsafe { MbufArena::open(&file.0) };
+
+        assert!(matches!(result, Err(DataChunkError::InvalidLayout)));
+
+        Ok(())
+    }
+
+    #[test]
+    fn arena_rejects_foreign_architecture() -> Result<()> {
+        let file = TempPath::new("arena-foreign");
+
+        MbufArena::create(
+            &file.0,
+            [OwnedDataChunk::from_data(b"hello world".to_vec())?],
+        )?;
+
+        let original = fs::read(&file.0)?;
+
+        assert_eq!(original[5], BYTE_ORDER);
+        assert_eq!(original[6], USIZE_WIDTH);
+
+        for position in [5, 6] {
+            let mut bytes = original.clone();
+
+            bytes[position] ^= 0x0c;
+            fs::write(&file.0, &bytes)?;
+
+            // SAFETY: the file is private to this test and not modified.
+            let result = unsafe { MbufArena::open(&file.0) };
+
+            assert!(matches!(result, Err(DataChunkError::InvalidLayout)));
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,117 @@
+//! Parallel batch operations over many chunks.
+//!
+//! Every function processes its items on the rayon thread pool and returns one
+//! result per input item, in input order, so a failing item does not abort the batch.
+
+use ps_hash::Hash;
+use rayon::iter::{IntoParallelIterator, ParallelIterator};
+
+use crate::{utils, DataChunk, EncryptedDataChunk, Result, SerializedDataChunk};
+
+/// Hashes every item of `data`.
+pub fn hash_all<I>(data: I) -> Vec<Result<Hash>>
+where
+    I: IntoIterator,
+    I::Item: AsRef<[u8]> + Send,
+{
+    collect(data)
+        .into_par_iter()
+        .map(|data| Ok(ps_hash::hash(data.as_ref())?))
+        .collect()
+}
+
+/// Verifies the hash of every chunk; see [`DataChunk::verify`].
+pub fn verify_all<I>(chunks: I) -> Vec<Result<()>>
+where
+    I: IntoIterator,
+    I::Item: DataChunk + Send,
+{
+    collect(chunks)
+        .into_par_iter()
+        .map(|chunk| chunk.verify())
+        .collect()
+}
+
+/// Encrypts every chunk; see [`DataChunk::encrypt`].
+pub fn encrypt_all<I>(chunks: I) -> Vec<Result<EncryptedDataChunk>>
+where
+    I: IntoIterator,
+    I::Item: DataChunk + Send,
+{
+    collect(chunks)
+        .into_par_iter()
+        .map(|chunk| chunk.encrypt())
+        .collect()
+}
+
+/// Decrypts every `(ciphertext, key)` pair; see [`utils::decrypt`].
+pub fn decrypt_all<I, C>(pairs: I) -> Vec<Result<SerializedDataChunk>>
+where
+    I: IntoIterator<Item = (C, Hash)>,
+    C: AsRef<[u8]> + Send,
+{
+    collect(pairs)
+        .into_par_iter()
+        .map(|(ciphertext, key)| utils::decrypt(ciphertext, &key))
+        .collect()
+}
+
+fn collect<I: IntoIterator>(items: I) -> Vec<I::Item> {
+    items.into_iter().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{BorrowedDataChunk, DataChunkError, OwnedDataChunk};
+
+    #[test]
+    fn batch_roundtrip_preserves_order() -> Result<()> {
+        let inputs: Vec<Vec<u8>> = (0..64u8).map(|i| vec![i; usize::from(i) * 100]).collect();
+
+        let hashes = hash_all(&inputs);
+        let chunks = inputs
+            .iter()
+            .map(|input| BorrowedDataChunk::from_data(input))
+            .collect::<Result<Vec<_>>>()?;
+
+        for (hash, chunk) in hashes.into_iter().zip(&chunks) {
+            assert_eq!(hash?, chunk.hash());
+        }
+
+        let encrypted = encrypt_all(chunks.iter().map(DataChunk::borrow))
+            .into_iter()
+            .collect::<Result<Vec<_>>>()?;
+
+        let pairs = encrypted
+            .iter()
+            .map(|chunk| Ok((chunk.data_ref(), chunk.chunk_key().to_hash()?)))
+            .collect::<Result<Vec<_>>>()?;
+
+        let decrypted = decrypt_all(pairs);
+
+        for (decrypted, input) in decrypted.into_iter().zip(&inputs) {
+            assert_eq!(decrypted?.data_ref(), input.as_slice());
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn batch_reports_per_item_errors() -> Result<()> {
+        let good = OwnedDataChunk::from_data(b"hello world".to_vec())?;
+        let bad =
+            OwnedDataChunk::from_data_and_hash_unchecked(b"hello there".to_vec(), good.hash());
+
+        let results = verify_all([good.clone(), bad, good]);
+
+        assert!(results[0].is_ok());
+        assert!(matches!(
+            results[1],
+            Err(DataChunkError::HashMismatch { .. })
+        ));
+        assert!(results[2].is_ok());
+
+        Ok(())
+    }
+}
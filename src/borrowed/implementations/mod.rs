@@ -1,2 +1,4 @@
 mod as_ref;
 mod deref;
+#[cfg(feature = "serde")]
+mod serde;
@@ -0,0 +1,9 @@
+use serde::{Serialize, Serializer};
+
+use crate::{utils::serde_chunk, BorrowedDataChunk};
+
+impl Serialize for BorrowedDataChunk<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde_chunk::serialize(self, serializer)
+    }
+}
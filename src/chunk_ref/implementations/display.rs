@@ -0,0 +1,17 @@
+use std::fmt::{Display, Formatter, Result};
+
+use crate::ChunkRef;
+
+impl Display for ChunkRef {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        let key = self.chunk_key().to_hash().map_err(|_| std::fmt::Error)?;
+
+        write!(f, "{}.{key}", self.hash_ref())?;
+
+        if let Some(length) = self.length() {
+            write!(f, ".{length}")?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,34 @@
+use std::str::FromStr;
+
+use ps_hash::{Hash, HASH_SIZE};
+
+use crate::{ChunkKey, ChunkRef, DataChunkError};
+
+impl FromStr for ChunkRef {
+    type Err = DataChunkError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let mut parts = value.split('.');
+
+        let (Some(hash), Some(key), length, None) =
+            (parts.next(), parts.next(), parts.next(), parts.next())
+        else {
+            return Err(DataChunkError::InvalidChunkRef);
+        };
+
+        if hash.len() != HASH_SIZE || key.len() != HASH_SIZE {
+            return Err(DataChunkError::InvalidChunkRef);
+        }
+
+        let length = length
+            .map(str::parse)
+            .transpose()
+            .map_err(|_| DataChunkError::InvalidChunkRef)?;
+
+        Ok(Self::from_parts(
+            Hash::try_from(hash)?,
+            ChunkKey::new(&Hash::try_from(key)?),
+            length,
+        ))
+    }
+}
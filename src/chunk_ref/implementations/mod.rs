@@ -0,0 +1,4 @@
+mod display;
+mod from_str;
+#[cfg(feature = "serde")]
+mod serde;
@@ -0,0 +1,52 @@
+use std::fmt::Formatter;
+
+use serde::{de::Visitor, Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::ChunkRef;
+
+impl Serialize for ChunkRef {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for ChunkRef {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct ChunkRefVisitor;
+
+        impl Visitor<'_> for ChunkRefVisitor {
+            type Value = ChunkRef;
+
+            fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+                formatter.write_str("a chunk reference")
+            }
+
+            fn visit_str<E: serde::de::Error>(self, value: &str) -> Result<Self::Value, E> {
+                value.parse().map_err(E::custom)
+            }
+        }
+
+        deserializer.deserialize_str(ChunkRefVisitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{BorrowedDataChunk, ChunkRef, DataChunk, Result};
+
+    #[test]
+    fn serde_roundtrip() -> Result<()> {
+        let encrypted = BorrowedDataChunk::from_data(b"hello world")?.encrypt()?;
+        let chunk_ref = ChunkRef::from(&encrypted).with_length(11);
+
+        let json = serde_json::to_string(&chunk_ref).map_err(std::io::Error::other)?;
+
+        assert_eq!(json, format!("\"{chunk_ref}\""));
+
+        let parsed: ChunkRef = serde_json::from_str(&json).map_err(std::io::Error::other)?;
+
+        assert_eq!(parsed, chunk_ref);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,253 @@
+mod implementations;
+
+use ps_hash::Hash;
+
+use crate::{
+    utils::{self, AsHashBytes},
+    ChunkKey, DataChunk, DataChunkError, EncryptedDataChunk, Result, SerializedDataChunk,
+};
+
+/// Everything needed to fetch and decrypt an encrypted chunk.
+///
+/// The textual form is `hash.key`, or `hash.key.length` if the plaintext
+/// length is known, and can be parsed back using [`str::parse`].
+///
+/// The decryption key is held in a [`ChunkKey`].
+#[derive(
+    Clone,
+    Debug,
+    Hash,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    rkyv::Archive,
+    rkyv::Serialize,
+    rkyv::Deserialize,
+)]
+pub struct ChunkRef {
+    #[rkyv(with = AsHashBytes)]
+    hash: Hash,
+    #[rkyv(with = AsHashBytes)]
+    key: ChunkKey,
+    length: Option<u64>,
+}
+
+impl ChunkRef {
+    /// Creates a [`ChunkRef`] from the hash of a ciphertext and its decryption key.
+    #[must_use]
+    pub fn new(hash: Hash, key: Hash) -> Self {
+        Self::from_parts(hash, ChunkKey::new(&key), None)
+    }
+
+    /// Creates a [`ChunkRef`] with an optional plaintext length.
+    pub(crate) const fn from_parts(hash: Hash, key: ChunkKey, length: Option<u64>) -> Self {
+        Self { hash, key, length }
+    }
+
+    /// Records the length of the plaintext, which [`Self::resolve`] then verifies.
+    #[must_use]
+    pub const fn with_length(mut self, length: u64) -> Self {
+        self.length = Some(length);
+        self
+    }
+
+    /// Returns the hash of the ciphertext.
+    #[must_use]
+    pub const fn hash(&self) -> Hash {
+        self.hash
+    }
+
+    #[must_use]
+    pub const fn hash_ref(&self) -> &Hash {
+        &self.hash
+    }
+
+    /// Returns the decryption key.
+    #[cfg(not(feature = "zeroize"))]
+    #[must_use]
+    pub const fn key(&self) -> Hash {
+        self.key.get()
+    }
+
+    #[cfg(not(feature = "zeroize"))]
+    #[must_use]
+    pub const fn key_ref(&self) -> &Hash {
+        self.key.get_ref()
+    }
+
+    /// Returns the decryption key.
+    ///
+    /// With the `zeroize` feature, this is the only accessor for the key.
+    #[must_use]
+    pub const fn chunk_key(&self) -> &ChunkKey {
+        &self.key
+    }
+
+    /// Returns the length of the plaintext, if known.
+    #[must_use]
+    pub const fn length(&self) -> Option<u64> {
+        self.length
+    }
+
+    /// Fetches the ciphertext through `lookup`, verifies its hash, and decrypts it.
+    pub fn resolve<F, C>(&self, lookup: F) -> Result<SerializedDataChunk>
+    where
+        F: FnOnce(&Hash) -> Result<C>,
+        C: DataChunk,
+    {
+        let chunk = lookup(&self.hash)?;
+
+        utils::verify_hash(chunk.data_ref(), &self.hash)?;
+
+        let decrypted = self.key.decrypt(chunk.data_ref())?;
+
+        match self.length {
+            Some(length) if length != decrypted.data_length() as u64 => {
+                Err(DataChunkError::InvalidLayout)
+            }
+            _ => Ok(decrypted),
+        }
+    }
+}
+
+impl ArchivedChunkRef {
+    /// Returns the hash of the ciphertext.
+    pub fn hash(&self) -> Result<Hash> {
+        Ok(Hash::validate(self.hash)?)
+    }
+
+    /// Returns the decryption key.
+    #[cfg(not(feature = "zeroize"))]
+    pub fn key(&self) -> Result<Hash> {
+        Ok(Hash::validate(self.key)?)
+    }
+
+    /// Returns the decryption key.
+    ///
+    /// With the `zeroize` feature, this is the only accessor for the key.
+    pub fn chunk_key(&self) -> Result<ChunkKey> {
+        ChunkKey::from_bytes(&self.key)
+    }
+
+    /// Returns the length of the plaintext, if known.
+    #[must_use]
+    pub fn length(&self) -> Option<u64> {
+        self.length.as_ref().map(|length| length.to_native())
+    }
+
+    /// Validates the archived hashes and returns a [`ChunkRef`].
+    pub fn to_chunk_ref(&self) -> Result<ChunkRef> {
+        Ok(ChunkRef::from_parts(
+            self.hash()?,
+            self.chunk_key()?,
+            self.length(),
+        ))
+    }
+}
+
+impl From<&EncryptedDataChunk> for ChunkRef {
+    fn from(chunk: &EncryptedDataChunk) -> Self {
+        Self::from_parts(*chunk.hash_ref(), chunk.chunk_key().clone(), None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{manifest::ManifestEntry, AlignedDataChunk, BorrowedDataChunk, OwnedDataChunk};
+
+    fn encrypted_sample() -> Result<(OwnedDataChunk, ChunkRef)> {
+        let data = b"hello world";
+        let encrypted = BorrowedDataChunk::from_data(data)?.encrypt()?;
+        let chunk_ref = ChunkRef::from(&encrypted).with_length(data.len() as u64);
+
+        Ok((encrypted.into_owned(), chunk_ref))
+    }
+
+    #[test]
+    fn text_roundtrip() -> Result<()> {
+        let (_, chunk_ref) = encrypted_sample()?;
+
+        let text = chunk_ref.to_string();
+
+        assert_eq!(text.parse::<ChunkRef>()?, chunk_ref);
+        assert_eq!(text.matches('.').count(), 2);
+
+        let without_length =
+            ChunkRef::from_parts(chunk_ref.hash(), chunk_ref.chunk_key().clone(), None);
+
+        assert_eq!(
+            without_length.to_string().parse::<ChunkRef>()?,
+            without_length
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn parse_rejects_malformed_text() -> Result<()> {
+        let (_, chunk_ref) = encrypted_sample()?;
+        let text = chunk_ref.to_string();
+
+        assert!("".parse::<ChunkRef>().is_err());
+        assert!(chunk_ref.hash().to_string().parse::<ChunkRef>().is_err());
+        assert!(format!("{text}.1").parse::<ChunkRef>().is_err());
+        assert!(text.replace(".11", ".eleven").parse::<ChunkRef>().is_err());
+        assert!(text.replacen('.', ".!", 1).parse::<ChunkRef>().is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn resolve_decrypts_and_verifies() -> Result<()> {
+        let (ciphertext, chunk_ref) = encrypted_sample()?;
+
+        let decrypted = chunk_ref.resolve(|_| Ok(ciphertext.clone()))?;
+
+        assert_eq!(decrypted.data_ref(), b"hello world");
+
+        let wrong_length =
+            ChunkRef::from_parts(chunk_ref.hash(), chunk_ref.chunk_key().clone(), None)
+                .with_length(3);
+
+        assert!(matches!(
+            wrong_length.resolve(|_| Ok(ciphertext.clone())),
+            Err(DataChunkError::InvalidLayout)
+        ));
+
+        let other = OwnedDataChunk::from_data(b"other".to_vec())?;
+
+        assert!(matches!(
+            chunk_ref.resolve(|_| Ok(other)),
+            Err(DataChunkError::HashMismatch { .. })
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn rkyv_roundtrip() -> Result<()> {
+        let (_, chunk_ref) = encrypted_sample()?;
+
+        let chunk = AlignedDataChunk::try_from(&chunk_ref)?;
+        let archived = chunk.try_as::<ChunkRef>()?;
+
+        assert_eq!(archived.to_chunk_ref()?, chunk_ref);
+
+        Ok(())
+    }
+
+    #[test]
+    fn debug_does_not_leak_key() -> Result<()> {
+        let (_, chunk_ref) = encrypted_sample()?;
+        let key = chunk_ref.chunk_key().to_hash()?;
+        let entry = ManifestEntry::encrypted(chunk_ref.hash(), key, 11);
+        let key = key.to_string();
+
+        assert!(!format!("{chunk_ref:?}").contains(&key));
+        assert!(!format!("{entry:?}").contains(&key));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,131 @@
+use std::io::{ErrorKind, Read};
+
+use bytes::Bytes;
+
+use super::{gear::GEAR, ChunkerConfig};
+use crate::{OwnedDataChunk, Result};
+
+/// Finds the end of the first content-defined chunk in `data`.
+///
+/// Uses the `FastCDC` gear hash with normalized chunking. The returned value
+/// is always within `min_size..=max_size`, unless `data` is shorter than that.
+#[must_use]
+pub fn cut_point(data: &[u8], config: &ChunkerConfig) -> usize {
+    if data.len() <= config.min_size() {
+        return data.len();
+    }
+
+    let max = data.len().min(config.max_size());
+    let normal = config.avg_size().min(max);
+    let mask_small = config.mask_small();
+    let mask_large = config.mask_large();
+
+    let mut fingerprint = 0u64;
+    let mut index = config.min_size();
+
+    while index < normal {
+        fingerprint = (fingerprint << 1).wrapping_add(GEAR[usize::from(data[index])]);
+        index += 1;
+
+        if fingerprint & mask_small == 0 {
+            return index;
+        }
+    }
+
+    while index < max {
+        fingerprint = (fingerprint << 1).wrapping_add(GEAR[usize::from(data[index])]);
+        index += 1;
+
+        if fingerprint & mask_large == 0 {
+            return index;
+        }
+    }
+
+    max
+}
+
+/// Splits a byte stream into content-defined [`OwnedDataChunk`]s.
+///
+/// Boundaries depend only on the surrounding bytes, so inserting or removing
+/// data only affects the chunks around the edit. Each chunk is copied out of
+/// the read buffer, so it keeps no more memory alive than its own bytes.
+pub struct CdcChunker<R: Read> {
+    reader: R,
+    config: ChunkerConfig,
+    /// Holds `filled` buffered bytes, followed by initialized space to read into.
+    buffer: Vec<u8>,
+    filled: usize,
+    eof: bool,
+}
+
+impl<R: Read> CdcChunker<R> {
+    pub fn new(reader: R) -> Self {
+        Self::with_config(reader, ChunkerConfig::default())
+    }
+
+    pub fn with_config(reader: R, config: ChunkerConfig) -> Self {
+        Self {
+            reader,
+            config,
+            buffer: Vec::new(),
+            filled: 0,
+            eof: false,
+        }
+    }
+
+    #[must_use]
+    pub const fn config(&self) -> &ChunkerConfig {
+        &self.config
+    }
+
+    /// Extracts the underlying reader, discarding any buffered bytes.
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+
+    /// Reads until `max_size` bytes are buffered or the reader is exhausted.
+    fn fill(&mut self) -> Result<()> {
+        let max_size = self.config.max_size();
+
+        // The buffer is only zeroed once; chunks are shifted out of it in place.
+        if !self.eof {
+            self.buffer.resize(max_size, 0);
+        }
+
+        while !self.eof && self.filled < max_size {
+            match self.reader.read(&mut self.buffer[self.filled..]) {
+                Ok(0) => self.eof = true,
+                Ok(read) => self.filled += read,
+                Err(err) if err.kind() == ErrorKind::Interrupted => {}
+                Err(err) => return Err(err.into()),
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Returns the next chunk, or `None` once the reader is exhausted.
+    pub fn next_chunk(&mut self) -> Result<Option<OwnedDataChunk>> {
+        self.fill()?;
+
+        if self.filled == 0 {
+            return Ok(None);
+        }
+
+        let length = cut_point(&self.buffer[..self.filled], &self.config);
+        let data = Bytes::copy_from_slice(&self.buffer[..length]);
+
+        self.buffer.copy_within(length..self.filled, 0);
+        self.filled -= length;
+
+        Ok(Some(OwnedDataChunk::from_bytes(data)?))
+    }
+}
+
+impl<R: Read> Iterator for CdcChunker<R> {
+    type Item = Result<OwnedDataChunk>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_chunk().transpose()
+    }
+}
@@ -0,0 +1,77 @@
+use crate::{DataChunkError, Result};
+
+/// Size bounds used by [`crate::CdcChunker`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ChunkerConfig {
+    min: usize,
+    avg: usize,
+    max: usize,
+}
+
+impl ChunkerConfig {
+    /// 16 KiB
+    pub const DEFAULT_MIN_SIZE: usize = 16 << 10;
+    /// 64 KiB
+    pub const DEFAULT_AVG_SIZE: usize = 64 << 10;
+    /// 256 KiB
+    pub const DEFAULT_MAX_SIZE: usize = 256 << 10;
+
+    /// Creates a new [`ChunkerConfig`].
+    ///
+    /// Sizes must satisfy `0 < min_size <= avg_size <= max_size`.
+    pub const fn new(min_size: usize, avg_size: usize, max_size: usize) -> Result<Self> {
+        if min_size == 0 || min_size > avg_size || avg_size > max_size {
+            return Err(DataChunkError::InvalidChunkerConfig);
+        }
+
+        Ok(Self {
+            min: min_size,
+            avg: avg_size,
+            max: max_size,
+        })
+    }
+
+    #[must_use]
+    pub const fn min_size(&self) -> usize {
+        self.min
+    }
+
+    #[must_use]
+    pub const fn avg_size(&self) -> usize {
+        self.avg
+    }
+
+    #[must_use]
+    pub const fn max_size(&self) -> usize {
+        self.max
+    }
+
+    /// Mask applied before `avg_size` is reached; harder to match.
+    pub(crate) const fn mask_small(&self) -> u64 {
+        mask(self.avg.ilog2() + 2)
+    }
+
+    /// Mask applied after `avg_size` is reached; easier to match.
+    pub(crate) const fn mask_large(&self) -> u64 {
+        mask(self.avg.ilog2().saturating_sub(2))
+    }
+}
+
+impl Default for ChunkerConfig {
+    fn default() -> Self {
+        Self {
+            min: Self::DEFAULT_MIN_SIZE,
+            avg: Self::DEFAULT_AVG_SIZE,
+            max: Self::DEFAULT_MAX_SIZE,
+        }
+    }
+}
+
+/// Selects the `bits` most significant bits, which carry the most history.
+const fn mask(bits: u32) -> u64 {
+    match bits {
+        0 => 0,
+        64.. => u64::MAX,
+        bits => !(u64::MAX >> bits),
+    }
+}
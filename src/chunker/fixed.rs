@@ -0,0 +1,75 @@
+use crate::{
+    utils::{round_up, SIZE_ALIGNMENT},
+    BorrowedDataChunk, DataChunkError, Result,
+};
+
+/// Splits a slice into fixed-size [`BorrowedDataChunk`]s without copying.
+///
+/// The block size is rounded up to a multiple of `1 << SIZE_ALIGNMENT`.
+/// Every block except the last is exactly [`Self::block_size`] bytes long.
+#[derive(Clone, Debug)]
+pub struct FixedChunker<'lt> {
+    data: &'lt [u8],
+    block_size: usize,
+}
+
+impl<'lt> FixedChunker<'lt> {
+    /// 64 KiB
+    pub const DEFAULT_BLOCK_SIZE: usize = 64 << 10;
+
+    #[must_use]
+    pub const fn new(data: &'lt [u8]) -> Self {
+        Self {
+            data,
+            block_size: Self::DEFAULT_BLOCK_SIZE,
+        }
+    }
+
+    /// Creates a [`FixedChunker`] with a custom block size.
+    ///
+    /// `block_size` is rounded up to the nearest multiple of `1 << SIZE_ALIGNMENT`.
+    pub const fn with_block_size(data: &'lt [u8], block_size: usize) -> Result<Self> {
+        let block_size = round_up(block_size, SIZE_ALIGNMENT);
+
+        if block_size == 0 {
+            return Err(DataChunkError::InvalidChunkerConfig);
+        }
+
+        Ok(Self { data, block_size })
+    }
+
+    #[must_use]
+    pub const fn block_size(&self) -> usize {
+        self.block_size
+    }
+
+    /// Returns the bytes which have not been chunked yet.
+    #[must_use]
+    pub const fn remainder(&self) -> &'lt [u8] {
+        self.data
+    }
+}
+
+impl<'lt> Iterator for FixedChunker<'lt> {
+    type Item = Result<BorrowedDataChunk<'lt>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.data.is_empty() {
+            return None;
+        }
+
+        let (block, rest) = self.data.split_at(self.block_size.min(self.data.len()));
+
+        self.data = rest;
+
+        Some(BorrowedDataChunk::from_data(block))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let count = self.data.len().div_ceil(self.block_size);
+
+        (count, Some(count))
+    }
+}
+
+impl ExactSizeIterator for FixedChunker<'_> {}
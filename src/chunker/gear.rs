@@ -0,0 +1,30 @@
+/// Seed used to generate [`GEAR`]; changing it changes every cut point.
+const SEED: u64 = 0x7073_2d64_6174_6163;
+
+/// Random lookup table used by the gear rolling hash.
+///
+/// The table is generated at compile time from a fixed seed, so chunk
+/// boundaries are stable across builds and platforms.
+pub const GEAR: [u64; 256] = generate(SEED);
+
+const fn generate(seed: u64) -> [u64; 256] {
+    let mut table = [0u64; 256];
+    let mut state = seed;
+    let mut index = 0;
+
+    while index < table.len() {
+        // splitmix64
+        state = state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+
+        let mut value = state;
+
+        value = (value ^ (value >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        value = (value ^ (value >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        value ^= value >> 31;
+
+        table[index] = value;
+        index += 1;
+    }
+
+    table
+}
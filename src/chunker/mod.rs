@@ -0,0 +1,150 @@
+mod cdc;
+mod config;
+mod fixed;
+mod gear;
+
+pub use cdc::*;
+pub use config::*;
+pub use fixed::*;
+
+#[allow(clippy::expect_used)]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{test_utils::sample, utils::SIZE_ALIGNMENT, DataChunk, OwnedDataChunk, Result};
+
+    fn chunk(data: &[u8], config: ChunkerConfig) -> Result<Vec<OwnedDataChunk>> {
+        CdcChunker::with_config(data, config).collect()
+    }
+
+    /// Returns at most 100 bytes per read, like a pipe or socket.
+    struct ShortReads<'lt>(&'lt [u8]);
+
+    impl std::io::Read for ShortReads<'_> {
+        fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+            let length = buf.len().min(self.0.len()).min(100);
+
+            buf[..length].copy_from_slice(&self.0[..length]);
+            self.0 = &self.0[length..];
+
+            Ok(length)
+        }
+    }
+
+    #[test]
+    fn chunks_reassemble_within_bounds() -> Result<()> {
+        let config = ChunkerConfig::new(256, 1024, 4096)?;
+        let data = sample(100_000);
+        let chunks = chunk(&data, config)?;
+
+        let (last, rest) = chunks.split_last().expect("input is not empty");
+
+        for chunk in rest {
+            assert!(chunk.data_ref().len() >= config.min_size());
+            assert!(chunk.data_ref().len() <= config.max_size());
+        }
+
+        assert!(last.data_ref().len() <= config.max_size());
+
+        let reassembled: Vec<u8> = chunks.iter().flat_map(|c| c.data_ref().to_vec()).collect();
+
+        assert_eq!(reassembled, data);
+
+        Ok(())
+    }
+
+    #[test]
+    fn insertion_only_changes_nearby_chunks() -> Result<()> {
+        let config = ChunkerConfig::new(256, 1024, 4096)?;
+        let original = sample(100_000);
+
+        let mut modified = original.clone();
+        modified.splice(50_000..50_000, *b"inserted bytes");
+
+        let before = chunk(&original, config)?;
+        let after = chunk(&modified, config)?;
+
+        let unchanged = after.iter().filter(|c| before.contains(c)).count();
+
+        assert!(after.len() - unchanged <= 3);
+
+        Ok(())
+    }
+
+    #[test]
+    fn short_reads_yield_the_same_chunks() -> Result<()> {
+        let config = ChunkerConfig::new(256, 1024, 4096)?;
+        let data = sample(100_000);
+        let chunks =
+            CdcChunker::with_config(ShortReads(&data), config).collect::<Result<Vec<_>>>()?;
+
+        assert_eq!(chunks, chunk(&data, config)?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn chunks_do_not_share_the_read_buffer() -> Result<()> {
+        let config = ChunkerConfig::new(256, 1024, 4096)?;
+        let data = sample(100_000);
+
+        let chunks = chunk(&data, config)?;
+
+        for chunk in chunks {
+            let length = chunk.data_ref().len();
+            let bytes = chunk
+                .into_bytes()
+                .try_into_mut()
+                .expect("chunk is the sole owner of its allocation");
+
+            assert_eq!(bytes.capacity(), length);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn empty_input_yields_no_chunks() -> Result<()> {
+        assert!(chunk(&[], ChunkerConfig::default())?.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn rejects_invalid_config() {
+        assert!(ChunkerConfig::new(0, 1024, 4096).is_err());
+        assert!(ChunkerConfig::new(2048, 1024, 4096).is_err());
+        assert!(ChunkerConfig::new(256, 8192, 4096).is_err());
+    }
+
+    #[test]
+    fn fixed_blocks_are_aligned_and_borrowed() -> Result<()> {
+        let data = sample(10_000);
+        let chunker = FixedChunker::with_block_size(&data, 1000)?;
+
+        assert_eq!(
+            chunker.block_size(),
+            1000_usize.next_multiple_of(1 << SIZE_ALIGNMENT)
+        );
+        assert_eq!(chunker.len(), 10);
+
+        let chunks: Vec<_> = chunker.collect::<Result<_>>()?;
+        let mut offset = 0;
+
+        for chunk in &chunks {
+            assert_eq!(chunk.data_ref().as_ptr(), data[offset..].as_ptr());
+
+            offset += chunk.data_ref().len();
+        }
+
+        assert_eq!(offset, data.len());
+        assert_eq!(chunks[9].data_ref().len(), 10_000 - 9 * 1000);
+
+        Ok(())
+    }
+
+    #[test]
+    fn fixed_rejects_zero_block_size() {
+        assert!(FixedChunker::with_block_size(&[1, 2, 3], 0).is_err());
+    }
+}
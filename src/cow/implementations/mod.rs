@@ -2,3 +2,5 @@ mod as_ref;
 mod datachunk;
 mod deref;
 mod from;
+#[cfg(feature = "serde")]
+mod serde;
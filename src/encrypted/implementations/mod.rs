@@ -1 +1,3 @@
 mod as_ref;
+#[cfg(feature = "serde")]
+mod serde;
@@ -0,0 +1,13 @@
+use serde::{Serialize, Serializer};
+
+use crate::{utils::serde_chunk, EncryptedDataChunk};
+
+/// Serializes an [`EncryptedDataChunk`]'s ciphertext and hash, but **not** its key.
+///
+/// The result deserializes as an [`crate::OwnedDataChunk`].
+/// Use [`crate::encrypted::with_key`] to include the key.
+impl Serialize for EncryptedDataChunk {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde_chunk::serialize(self, serializer)
+    }
+}
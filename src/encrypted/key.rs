@@ -0,0 +1,161 @@
+use std::{
+    borrow::Cow,
+    fmt::{Debug, Formatter},
+};
+
+use ps_hash::{Hash, HASH_SIZE_BIN};
+
+#[cfg(feature = "zeroize")]
+use crate::utils::hash_to_bytes;
+use crate::{utils, Result, SerializedDataChunk};
+
+/// The key needed to decrypt an [`crate::EncryptedDataChunk`].
+///
+/// `Debug` never prints the key, so types holding a [`ChunkKey`] can derive
+/// `Debug` without leaking it.
+///
+/// With the `zeroize` feature, the key is held in its binary form, is
+/// overwritten with zeroes when dropped, and is never handed out as a
+/// [`struct@Hash`] by reference. Decrypting still requires a [`struct@Hash`],
+/// which [`Self::to_hash`] and [`Self::decrypt`] create on the fly; those
+/// copies, including any made by [`ps_cypher`], are not wiped.
+#[derive(Clone, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct ChunkKey(
+    #[cfg(not(feature = "zeroize"))] Hash,
+    #[cfg(feature = "zeroize")] [u8; HASH_SIZE_BIN],
+);
+
+#[cfg(not(feature = "zeroize"))]
+impl ChunkKey {
+    #[must_use]
+    pub const fn new(key: &Hash) -> Self {
+        Self(*key)
+    }
+
+    /// Creates a [`ChunkKey`] from its binary form, validating it.
+    pub fn from_bytes(bytes: &[u8; HASH_SIZE_BIN]) -> Result<Self> {
+        Ok(Self(Hash::validate(bytes)?))
+    }
+
+    #[must_use]
+    pub const fn get(&self) -> Hash {
+        self.0
+    }
+
+    #[must_use]
+    pub const fn get_ref(&self) -> &Hash {
+        &self.0
+    }
+
+    /// Returns this key as a [`struct@Hash`].
+    #[allow(clippy::unnecessary_wraps)]
+    pub const fn to_hash(&self) -> Result<Hash> {
+        Ok(self.0)
+    }
+
+    /// Returns the binary form of this key.
+    pub(crate) fn to_bytes(&self) -> Cow<'_, [u8; HASH_SIZE_BIN]> {
+        Cow::Owned(utils::hash_to_bytes(&self.0))
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl ChunkKey {
+    #[must_use]
+    pub fn new(key: &Hash) -> Self {
+        Self(hash_to_bytes(key))
+    }
+
+    /// Creates a [`ChunkKey`] from its binary form, validating it.
+    pub fn from_bytes(bytes: &[u8; HASH_SIZE_BIN]) -> Result<Self> {
+        Hash::validate(bytes)?;
+
+        Ok(Self(*bytes))
+    }
+
+    /// Returns this key as a [`struct@Hash`], which is not wiped when dropped.
+    pub fn to_hash(&self) -> Result<Hash> {
+        Ok(Hash::validate(self.0)?)
+    }
+
+    /// Returns the binary form of this key.
+    pub(crate) const fn to_bytes(&self) -> Cow<'_, [u8; HASH_SIZE_BIN]> {
+        Cow::Borrowed(&self.0)
+    }
+}
+
+impl ChunkKey {
+    /// Decrypts `ciphertext` with this key; see [`utils::decrypt`].
+    pub fn decrypt(&self, ciphertext: impl AsRef<[u8]>) -> Result<SerializedDataChunk> {
+        utils::decrypt(ciphertext, &self.to_hash()?)
+    }
+}
+
+impl Debug for ChunkKey {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str("ChunkKey(<REDACTED>)")
+    }
+}
+
+impl From<&Hash> for ChunkKey {
+    fn from(value: &Hash) -> Self {
+        Self::new(value)
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl Drop for ChunkKey {
+    fn drop(&mut self) {
+        use zeroize::Zeroize;
+
+        self.0.zeroize();
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl zeroize::ZeroizeOnDrop for ChunkKey {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{BorrowedDataChunk, DataChunk};
+
+    #[test]
+    fn debug_does_not_leak_key() -> Result<()> {
+        let encrypted = BorrowedDataChunk::from_data(b"hello world")?.encrypt()?;
+        let key = encrypted.chunk_key().to_hash()?.to_string();
+
+        assert!(!format!("{encrypted:?}").contains(&key));
+        assert_eq!(
+            format!("{:?}", encrypted.chunk_key()),
+            "ChunkKey(<REDACTED>)"
+        );
+
+        Ok(())
+    }
+
+    #[cfg(not(feature = "zeroize"))]
+    #[test]
+    fn hash_accessors_remain_without_zeroize() -> Result<()> {
+        let encrypted = BorrowedDataChunk::from_data(b"hello world")?.encrypt()?;
+
+        let decrypted = utils::decrypt(encrypted.data_ref(), &encrypted.key())?;
+
+        assert_eq!(decrypted.data_ref(), b"hello world");
+        assert_eq!(encrypted.key_ref(), &encrypted.chunk_key().to_hash()?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn binary_form_roundtrip() -> Result<()> {
+        let hash = ps_hash::hash(b"hello world")?;
+        let key = ChunkKey::new(&hash);
+
+        assert_eq!(ChunkKey::from_bytes(&key.to_bytes())?, key);
+        assert_eq!(key.to_hash()?, hash);
+        assert!(ChunkKey::from_bytes(&[0xff; HASH_SIZE_BIN]).is_err());
+
+        Ok(())
+    }
+}
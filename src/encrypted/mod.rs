@@ -1,6 +1,8 @@
 mod implementations;
+mod key;
+#[cfg(feature = "serde")]
+pub mod with_key;
 
-use crate::utils;
 use crate::DataChunk;
 use crate::Result;
 use crate::SerializedDataChunk;
@@ -10,27 +12,39 @@ use ps_buffer::SharedBuffer;
 use ps_cypher::Encrypted;
 use ps_hash::Hash;
 
+pub use key::ChunkKey;
+
 #[derive(Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
 /// represents an encrypted chunk of data and the key needed to decrypt it
 pub struct EncryptedDataChunk {
     data: Buffer,
     hash: Hash,
-    key: Hash,
+    key: ChunkKey,
 }
 
 impl EncryptedDataChunk {
     /// Decrypts this `EncryptedDataChunk`.
     pub fn decrypt(&self) -> Result<SerializedDataChunk> {
-        utils::decrypt(self.data_ref(), &self.key)
+        self.key.decrypt(self.data_ref())
     }
 
+    #[cfg(not(feature = "zeroize"))]
     #[must_use]
     pub const fn key(&self) -> Hash {
-        self.key
+        self.key.get()
     }
 
+    #[cfg(not(feature = "zeroize"))]
     #[must_use]
     pub const fn key_ref(&self) -> &Hash {
+        self.key.get_ref()
+    }
+
+    /// Returns the decryption key.
+    ///
+    /// With the `zeroize` feature, this is the only accessor for the key.
+    #[must_use]
+    pub const fn chunk_key(&self) -> &ChunkKey {
         &self.key
     }
 }
@@ -61,7 +75,7 @@ impl From<Encrypted> for EncryptedDataChunk {
         Self {
             data: value.bytes,
             hash: value.hash,
-            key: value.key,
+            key: ChunkKey::new(&value.key),
         }
     }
 }
@@ -0,0 +1,99 @@
+//! Serializes an [`EncryptedDataChunk`] including its decryption key.
+//!
+//! Anyone who can read the output can decrypt the chunk, so this must be
+//! requested explicitly with `#[serde(with = "ps_datachunk::encrypted::with_key")]`.
+
+use bytes::Bytes;
+use ps_buffer::Buffer;
+use ps_cypher::Encrypted;
+use ps_hash::Hash;
+use serde::{de, ser, Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{
+    utils::{self, serde_chunk::RawBytes},
+    DataChunk, EncryptedDataChunk,
+};
+
+#[derive(Serialize)]
+#[serde(rename = "EncryptedDataChunk")]
+struct SerializeEncrypted<'lt> {
+    #[serde(with = "utils::serde_hash")]
+    hash: Hash,
+    #[serde(with = "utils::serde_hash")]
+    key: Hash,
+    data: RawBytes<'lt>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename = "EncryptedDataChunk")]
+struct DeserializeEncrypted {
+    #[serde(with = "utils::serde_hash")]
+    hash: Hash,
+    #[serde(with = "utils::serde_hash")]
+    key: Hash,
+    data: Bytes,
+}
+
+pub fn serialize<S: Serializer>(
+    chunk: &EncryptedDataChunk,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    SerializeEncrypted {
+        hash: chunk.hash(),
+        key: chunk.chunk_key().to_hash().map_err(ser::Error::custom)?,
+        data: RawBytes(chunk.data_ref()),
+    }
+    .serialize(serializer)
+}
+
+/// Deserializes an [`EncryptedDataChunk`], failing if its hash does not match its ciphertext.
+pub fn deserialize<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<EncryptedDataChunk, D::Error> {
+    let DeserializeEncrypted { hash, key, data } = DeserializeEncrypted::deserialize(deserializer)?;
+
+    utils::verify_hash(&data, &hash).map_err(de::Error::custom)?;
+
+    let bytes = Buffer::from_slice(&data).map_err(de::Error::custom)?;
+
+    Ok(Encrypted { bytes, hash, key }.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::{Deserialize, Serialize};
+
+    use crate::{BorrowedDataChunk, DataChunk, EncryptedDataChunk, OwnedDataChunk, Result};
+
+    #[derive(Serialize, Deserialize)]
+    struct Record {
+        #[serde(with = "super")]
+        chunk: EncryptedDataChunk,
+    }
+
+    #[test]
+    fn key_is_only_serialized_on_request() -> Result<()> {
+        let encrypted = BorrowedDataChunk::from_data(b"hello world")?.encrypt()?;
+        let key = encrypted.chunk_key().to_hash()?.to_string();
+
+        let keyless = serde_json::to_string(&encrypted).map_err(std::io::Error::other)?;
+
+        assert!(!keyless.contains(&key));
+
+        let owned: OwnedDataChunk =
+            serde_json::from_str(&keyless).map_err(std::io::Error::other)?;
+
+        assert_eq!(owned.hash(), encrypted.hash());
+
+        let json =
+            serde_json::to_string(&Record { chunk: encrypted }).map_err(std::io::Error::other)?;
+
+        assert!(json.contains(&key));
+
+        let record: Record = serde_json::from_str(&json).map_err(std::io::Error::other)?;
+
+        assert_eq!(record.chunk.decrypt()?.data_ref(), b"hello world");
+
+        Ok(())
+    }
+}
@@ -0,0 +1,199 @@
+#[cfg(feature = "serde")]
+mod serde;
+mod wrapped_ref;
+
+use std::fmt::{Debug, Formatter};
+
+use chacha20poly1305::{aead::Aead, ChaCha20Poly1305, KeyInit, Nonce};
+use ps_hash::{Hash, HASH_SIZE_BIN};
+
+use crate::{ChunkKey, DataChunkError, Result};
+
+pub use wrapped_ref::*;
+
+const ENCRYPTION_CONTEXT: &str = "ps-datachunk 2026-10-17 key wrapping encryption key";
+const NONCE_CONTEXT: &str = "ps-datachunk 2026-10-17 key wrapping nonce key";
+const KEY_SIZE: usize = 32;
+const NONCE_SIZE: usize = 12;
+const TAG_SIZE: usize = 16;
+
+/// Size of a [`WrappedKey`] in bytes.
+pub const WRAPPED_KEY_SIZE: usize = NONCE_SIZE + HASH_SIZE_BIN + TAG_SIZE;
+
+/// A secret used to wrap and unwrap chunk keys.
+///
+/// Wrapping is deterministic: the nonce is derived from the wrapped key,
+/// so equal chunk keys produce equal [`WrappedKey`]s under the same [`MasterKey`].
+/// This preserves deduplication, but reveals which wrapped keys are equal.
+#[derive(Clone)]
+pub struct MasterKey {
+    encryption_key: [u8; KEY_SIZE],
+    nonce_key: [u8; KEY_SIZE],
+}
+
+impl MasterKey {
+    /// Creates a [`MasterKey`] from 32 bytes of uniformly random key material.
+    #[must_use]
+    pub fn from_bytes(bytes: &[u8; KEY_SIZE]) -> Self {
+        Self::derive(bytes)
+    }
+
+    /// Derives a [`MasterKey`] from secret key material.
+    ///
+    /// `secret` must have high entropy; this is not a password hash.
+    #[must_use]
+    pub fn derive(secret: &[u8]) -> Self {
+        Self {
+            encryption_key: blake3::derive_key(ENCRYPTION_CONTEXT, secret),
+            nonce_key: blake3::derive_key(NONCE_CONTEXT, secret),
+        }
+    }
+
+    /// Encrypts `key` under this [`MasterKey`].
+    pub fn wrap(&self, key: &Hash) -> Result<WrappedKey> {
+        self.wrap_key(&ChunkKey::new(key))
+    }
+
+    /// Encrypts `key` under this [`MasterKey`].
+    pub fn wrap_key(&self, key: &ChunkKey) -> Result<WrappedKey> {
+        let plaintext = key.to_bytes();
+        let digest = blake3::keyed_hash(&self.nonce_key, &plaintext[..]);
+        let nonce = &digest.as_bytes()[..NONCE_SIZE];
+
+        let ciphertext = ChaCha20Poly1305::new(&self.encryption_key.into())
+            .encrypt(Nonce::from_slice(nonce), &plaintext[..])
+            .map_err(|_| DataChunkError::KeyWrap)?;
+
+        let mut wrapped = [0u8; WRAPPED_KEY_SIZE];
+
+        wrapped[..NONCE_SIZE].copy_from_slice(nonce);
+        wrapped[NONCE_SIZE..].copy_from_slice(&ciphertext);
+
+        Ok(WrappedKey(wrapped))
+    }
+
+    /// Decrypts a key previously wrapped by this [`MasterKey`].
+    ///
+    /// Fails with [`DataChunkError::KeyWrap`] if `wrapped` was produced by
+    /// a different [`MasterKey`] or has been tampered with.
+    pub fn unwrap(&self, wrapped: &WrappedKey) -> Result<Hash> {
+        self.unwrap_key(wrapped)?.to_hash()
+    }
+
+    /// Decrypts a key previously wrapped by this [`MasterKey`] into a [`ChunkKey`].
+    ///
+    /// See [`Self::unwrap`].
+    pub fn unwrap_key(&self, wrapped: &WrappedKey) -> Result<ChunkKey> {
+        let (nonce, ciphertext) = wrapped.0.split_at(NONCE_SIZE);
+
+        let plaintext = ChaCha20Poly1305::new(&self.encryption_key.into())
+            .decrypt(Nonce::from_slice(nonce), ciphertext)
+            .map_err(|_| DataChunkError::KeyWrap)?;
+
+        #[cfg(feature = "zeroize")]
+        let plaintext = zeroize::Zeroizing::new(plaintext);
+
+        ChunkKey::from_bytes(plaintext.as_slice().try_into()?)
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl Drop for MasterKey {
+    fn drop(&mut self) {
+        use zeroize::Zeroize;
+
+        self.encryption_key.zeroize();
+        self.nonce_key.zeroize();
+    }
+}
+
+impl Debug for MasterKey {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str("MasterKey(<REDACTED>)")
+    }
+}
+
+/// A chunk key encrypted under a [`MasterKey`].
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Hash,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    rkyv::Archive,
+    rkyv::Serialize,
+    rkyv::Deserialize,
+)]
+pub struct WrappedKey([u8; WRAPPED_KEY_SIZE]);
+
+impl WrappedKey {
+    #[must_use]
+    pub const fn from_bytes(bytes: [u8; WRAPPED_KEY_SIZE]) -> Self {
+        Self(bytes)
+    }
+
+    #[must_use]
+    pub const fn as_bytes(&self) -> &[u8; WRAPPED_KEY_SIZE] {
+        &self.0
+    }
+}
+
+impl TryFrom<&[u8]> for WrappedKey {
+    type Error = DataChunkError;
+
+    fn try_from(value: &[u8]) -> Result<Self> {
+        Ok(Self(value.try_into()?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn wrap_unwrap_roundtrip() -> Result<()> {
+        let master = MasterKey::derive(b"correct horse battery staple");
+        let key = ps_hash::hash(b"hello world")?;
+
+        let wrapped = master.wrap(&key)?;
+
+        assert_eq!(master.unwrap(&wrapped)?, key);
+        assert_eq!(master.unwrap_key(&wrapped)?, ChunkKey::new(&key));
+        assert_eq!(master.wrap_key(&ChunkKey::new(&key))?, wrapped);
+        assert_ne!(master.wrap(&ps_hash::hash(b"other")?)?, wrapped);
+
+        Ok(())
+    }
+
+    #[test]
+    fn unwrap_rejects_wrong_master_and_tampering() -> Result<()> {
+        let master = MasterKey::from_bytes(&[7; 32]);
+        let other = MasterKey::from_bytes(&[8; 32]);
+        let wrapped = master.wrap(&ps_hash::hash(b"hello world")?)?;
+
+        assert!(matches!(
+            other.unwrap(&wrapped),
+            Err(DataChunkError::KeyWrap)
+        ));
+
+        let mut bytes = *wrapped.as_bytes();
+        bytes[WRAPPED_KEY_SIZE - 1] ^= 1;
+
+        assert!(matches!(
+            master.unwrap(&WrappedKey::from_bytes(bytes)),
+            Err(DataChunkError::KeyWrap)
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn debug_is_redacted() {
+        let master = MasterKey::from_bytes(&[7; 32]);
+
+        assert_eq!(format!("{master:?}"), "MasterKey(<REDACTED>)");
+    }
+}
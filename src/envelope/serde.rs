@@ -0,0 +1,93 @@
+use bytes::Bytes;
+use ps_hash::Hash;
+use serde::{de::Error, Deserialize, Deserializer, Serialize, Serializer};
+
+use super::{WrappedChunkRef, WrappedKey};
+use crate::utils;
+
+/// Serializes a [`WrappedKey`] as its raw bytes.
+impl Serialize for WrappedKey {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_bytes(self.as_bytes())
+    }
+}
+
+/// Deserializes a [`WrappedKey`], failing if it has the wrong length.
+///
+/// The key is authenticated when it is unwrapped by [`super::MasterKey::unwrap`].
+impl<'de> Deserialize<'de> for WrappedKey {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let bytes = Bytes::deserialize(deserializer)?;
+
+        Self::try_from(&bytes[..]).map_err(D::Error::custom)
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename = "WrappedChunkRef")]
+struct Repr {
+    #[serde(with = "utils::serde_hash")]
+    hash: Hash,
+    wrapped_key: WrappedKey,
+    length: Option<u64>,
+}
+
+impl Serialize for WrappedChunkRef {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        Repr {
+            hash: self.hash(),
+            wrapped_key: *self.wrapped_key(),
+            length: self.length(),
+        }
+        .serialize(serializer)
+    }
+}
+
+/// Deserializes a [`WrappedChunkRef`], validating its hash.
+///
+/// The hash of the ciphertext is verified when the reference is resolved.
+impl<'de> Deserialize<'de> for WrappedChunkRef {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let Repr {
+            hash,
+            wrapped_key,
+            length,
+        } = Repr::deserialize(deserializer)?;
+
+        Ok(Self::new(hash, wrapped_key, length))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{BorrowedDataChunk, ChunkRef, DataChunk, MasterKey, Result, WrappedChunkRef};
+
+    #[test]
+    fn serde_roundtrip() -> Result<()> {
+        let master = MasterKey::derive(b"correct horse battery staple");
+        let encrypted = BorrowedDataChunk::from_data(b"hello world")?.encrypt()?;
+        let chunk_ref = ChunkRef::from(&encrypted).with_length(11);
+        let wrapped = chunk_ref.wrap(&master)?;
+
+        let json = serde_json::to_string(&wrapped).map_err(std::io::Error::other)?;
+        let parsed: WrappedChunkRef = serde_json::from_str(&json).map_err(std::io::Error::other)?;
+
+        assert_eq!(parsed, wrapped);
+        assert_eq!(parsed.unwrap(&master)?, chunk_ref);
+
+        let mut short: serde_json::Value =
+            serde_json::from_str(&json).map_err(std::io::Error::other)?;
+
+        if let Some(key) = short["wrapped_key"].as_array_mut() {
+            key.pop();
+        }
+
+        assert!(serde_json::from_value::<WrappedChunkRef>(short).is_err());
+
+        let invalid_hash = json.replace(&wrapped.hash().to_string(), "not a hash");
+
+        assert!(serde_json::from_str::<WrappedChunkRef>(&invalid_hash).is_err());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,105 @@
+use ps_hash::Hash;
+
+use super::{MasterKey, WrappedKey};
+use crate::{utils::AsHashBytes, ChunkRef, Result};
+
+/// A [`ChunkRef`] whose key is encrypted under a [`MasterKey`].
+///
+/// Safe to store on untrusted storage: the ciphertext can be fetched by its
+/// hash, but only holders of the [`MasterKey`] can decrypt it.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Hash,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    rkyv::Archive,
+    rkyv::Serialize,
+    rkyv::Deserialize,
+)]
+pub struct WrappedChunkRef {
+    #[rkyv(with = AsHashBytes)]
+    hash: Hash,
+    wrapped_key: WrappedKey,
+    length: Option<u64>,
+}
+
+impl WrappedChunkRef {
+    #[must_use]
+    pub const fn new(hash: Hash, wrapped_key: WrappedKey, length: Option<u64>) -> Self {
+        Self {
+            hash,
+            wrapped_key,
+            length,
+        }
+    }
+
+    /// Returns the hash of the ciphertext.
+    #[must_use]
+    pub const fn hash(&self) -> Hash {
+        self.hash
+    }
+
+    #[must_use]
+    pub const fn wrapped_key(&self) -> &WrappedKey {
+        &self.wrapped_key
+    }
+
+    /// Returns the length of the plaintext, if known.
+    #[must_use]
+    pub const fn length(&self) -> Option<u64> {
+        self.length
+    }
+
+    /// Unwraps the key, returning a [`ChunkRef`] which can be resolved.
+    pub fn unwrap(&self, master: &MasterKey) -> Result<ChunkRef> {
+        Ok(ChunkRef::from_parts(
+            self.hash,
+            master.unwrap_key(&self.wrapped_key)?,
+            self.length,
+        ))
+    }
+}
+
+impl ChunkRef {
+    /// Encrypts this reference's key under `master`.
+    pub fn wrap(&self, master: &MasterKey) -> Result<WrappedChunkRef> {
+        Ok(WrappedChunkRef::new(
+            self.hash(),
+            master.wrap_key(self.chunk_key())?,
+            self.length(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{AlignedDataChunk, BorrowedDataChunk, DataChunk};
+
+    #[test]
+    fn wrapped_ref_roundtrip() -> Result<()> {
+        let master = MasterKey::derive(b"correct horse battery staple");
+        let encrypted = BorrowedDataChunk::from_data(b"hello world")?.encrypt()?;
+        let chunk_ref = ChunkRef::from(&encrypted).with_length(11);
+
+        let wrapped = chunk_ref.wrap(&master)?;
+        let archived = AlignedDataChunk::try_from(&wrapped)?;
+        let restored: WrappedChunkRef = rkyv::from_bytes::<_, rancor::Error>(&archived)
+            .map_err(|_| crate::DataChunkError::InvalidArchive)?;
+
+        assert_eq!(restored, wrapped);
+        assert_eq!(restored.unwrap(&master)?, chunk_ref);
+
+        let decrypted = restored
+            .unwrap(&master)?
+            .resolve(|_| Ok(encrypted.borrow()))?;
+
+        assert_eq!(decrypted.data_ref(), b"hello world");
+
+        Ok(())
+    }
+}
@@ -1,5 +1,6 @@
 use std::array::TryFromSliceError;
 
+use ps_hash::Hash;
 use thiserror::Error;
 
 #[derive(Error, Debug)]
@@ -15,15 +16,37 @@ pub enum DataChunkError {
     #[error(transparent)]
     HashValidation(#[from] ps_hash::HashValidationError),
     #[error(transparent)]
+    Io(#[from] std::io::Error),
+    #[error(transparent)]
     Slice(#[from] TryFromSliceError),
     #[error("The data chunk was not correctly laid out")]
     InvalidLayout,
-    #[error("The hash of a chunk was incorrect")]
-    HashMismatch,
+    #[error("The memory region is too small or misaligned to hold an Mbuf")]
+    InvalidRegion,
+    #[error("Unsupported serialized chunk format version: {0}")]
+    UnsupportedVersion(u8),
+    #[error("Unsupported serialized chunk flags: {0:#04x}")]
+    UnsupportedFlags(u8),
+    #[error("The hash of a chunk was incorrect: expected {expected}, found {actual}")]
+    HashMismatch { expected: Hash, actual: Hash },
+    #[error("The stored hash of a chunk was corrupt")]
+    CorruptHash,
     #[error("Rkyv deserialization failed")]
     InvalidArchive,
     #[error("Rkyv serialization failed")]
     Serialization,
+    #[error("Failed to deserialize an archived value: {0}")]
+    Deserialization(rancor::Error),
+    #[error("Type fingerprint mismatch: expected {expected:#018x}, found {actual:#018x}")]
+    TypeMismatch { expected: u64, actual: u64 },
+    #[error("Wrapping or unwrapping a key failed")]
+    KeyWrap,
+    #[error("The chunk reference was malformed")]
+    InvalidChunkRef,
+    #[error("The requested chunk was not found")]
+    NotFound,
+    #[error("The chunker configuration was invalid")]
+    InvalidChunkerConfig,
 }
 
 pub type Result<T> = std::result::Result<T, DataChunkError>;
@@ -0,0 +1,7 @@
+use crate::LazyDataChunk;
+
+impl AsRef<[u8]> for LazyDataChunk {
+    fn as_ref(&self) -> &[u8] {
+        &self.data
+    }
+}
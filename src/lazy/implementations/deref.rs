@@ -0,0 +1,11 @@
+use std::ops::Deref;
+
+use crate::LazyDataChunk;
+
+impl Deref for LazyDataChunk {
+    type Target = [u8];
+
+    fn deref(&self) -> &Self::Target {
+        &self.data
+    }
+}
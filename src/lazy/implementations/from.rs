@@ -0,0 +1,26 @@
+use std::sync::OnceLock;
+
+use bytes::Bytes;
+
+use crate::{LazyDataChunk, OwnedDataChunk};
+
+impl From<Bytes> for LazyDataChunk {
+    fn from(value: Bytes) -> Self {
+        Self::from_bytes(value)
+    }
+}
+
+impl From<OwnedDataChunk> for LazyDataChunk {
+    fn from(value: OwnedDataChunk) -> Self {
+        Self {
+            hash: OnceLock::from(value.hash()),
+            data: value.into(),
+        }
+    }
+}
+
+impl From<LazyDataChunk> for Bytes {
+    fn from(value: LazyDataChunk) -> Self {
+        value.data
+    }
+}
@@ -0,0 +1,5 @@
+mod as_ref;
+mod deref;
+mod from;
+#[cfg(feature = "serde")]
+mod serde;
@@ -0,0 +1,26 @@
+use std::sync::OnceLock;
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{utils::serde_chunk, LazyDataChunk};
+
+/// Serializes a [`LazyDataChunk`], calculating its hash if necessary.
+impl Serialize for LazyDataChunk {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.try_hash_ref().map_err(serde::ser::Error::custom)?;
+
+        serde_chunk::serialize(self, serializer)
+    }
+}
+
+/// Deserializes a [`LazyDataChunk`]; its hash is verified, and therefore known.
+impl<'de> Deserialize<'de> for LazyDataChunk {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let (data, hash) = serde_chunk::deserialize(deserializer)?;
+
+        Ok(Self {
+            data,
+            hash: OnceLock::from(hash),
+        })
+    }
+}
@@ -0,0 +1,132 @@
+mod implementations;
+
+use std::sync::OnceLock;
+
+use bytes::Bytes;
+use ps_hash::Hash;
+
+use crate::{DataChunk, OwnedDataChunk, Result};
+
+/// A chunk of data whose hash is calculated on first use.
+///
+/// Construction never hashes; the hash is computed once, by the first call to
+/// [`LazyDataChunk::try_hash_ref`] or [`DataChunk::hash_ref`], and cached.
+#[derive(Debug, Clone, Default)]
+pub struct LazyDataChunk {
+    data: Bytes,
+    hash: OnceLock<Hash>,
+}
+
+impl LazyDataChunk {
+    #[must_use]
+    pub const fn from_bytes(data: Bytes) -> Self {
+        Self {
+            data,
+            hash: OnceLock::new(),
+        }
+    }
+
+    pub fn from_data<D>(data: D) -> Self
+    where
+        D: AsRef<[u8]> + Send + 'static,
+    {
+        Self::from_bytes(Bytes::from_owner(data))
+    }
+
+    /// Returns this chunk's bytes.
+    ///
+    /// This is a cheap clone of the underlying `Bytes` buffer.
+    #[must_use]
+    pub fn bytes(&self) -> Bytes {
+        self.data.clone()
+    }
+
+    /// Returns `true` if the hash has already been calculated.
+    #[must_use]
+    pub fn is_hashed(&self) -> bool {
+        self.hash.get().is_some()
+    }
+
+    /// Returns this chunk's hash, calculating it if necessary.
+    pub fn try_hash_ref(&self) -> Result<&Hash> {
+        if let Some(hash) = self.hash.get() {
+            return Ok(hash);
+        }
+
+        let hash = ps_hash::hash(&self.data)?;
+
+        Ok(self.hash.get_or_init(|| hash))
+    }
+
+    /// Converts this chunk into an [`OwnedDataChunk`], reusing its buffer.
+    pub fn try_into_owned(self) -> Result<OwnedDataChunk> {
+        let hash = *self.try_hash_ref()?;
+
+        Ok(OwnedDataChunk::from_parts_unchecked(self.data, hash))
+    }
+}
+
+impl DataChunk for LazyDataChunk {
+    fn data_ref(&self) -> &[u8] {
+        &self.data
+    }
+
+    /// Returns this chunk's hash, calculating it if necessary.
+    ///
+    /// # Panics
+    ///
+    /// Panics if hashing fails; use [`LazyDataChunk::try_hash_ref`] to handle the error.
+    fn hash_ref(&self) -> &Hash {
+        self.try_hash_ref()
+            .unwrap_or_else(|err| panic!("failed to hash LazyDataChunk: {err}"))
+    }
+
+    /// Transforms this [`DataChunk`] into [`Bytes`] without hashing it.
+    fn into_bytes(self) -> Bytes {
+        self.data
+    }
+
+    /// Transforms this chunk into an [`OwnedDataChunk`] without copying.
+    ///
+    /// # Panics
+    ///
+    /// Panics if hashing fails; use [`LazyDataChunk::try_into_owned`] to handle the error.
+    fn into_owned(self) -> OwnedDataChunk {
+        let hash = self.hash();
+
+        OwnedDataChunk::from_parts_unchecked(self.data, hash)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hash_is_calculated_once_on_demand() -> Result<()> {
+        let chunk = LazyDataChunk::from_data(b"hello world");
+
+        assert!(!chunk.is_hashed());
+        assert_eq!(chunk.data_ref(), b"hello world");
+        assert!(!chunk.is_hashed());
+
+        assert_eq!(*chunk.try_hash_ref()?, ps_hash::hash(b"hello world")?);
+        assert!(chunk.is_hashed());
+        assert!(std::ptr::eq(chunk.hash_ref(), chunk.try_hash_ref()?));
+
+        Ok(())
+    }
+
+    #[test]
+    fn into_owned_shares_buffer() -> Result<()> {
+        let bytes = Bytes::from_static(b"hello world");
+        let chunk = LazyDataChunk::from_bytes(bytes.clone());
+
+        let owned = chunk.try_into_owned()?;
+
+        assert_eq!(owned.data_ref().as_ptr(), bytes.as_ptr());
+        assert_eq!(owned.hash(), ps_hash::hash(b"hello world")?);
+
+        Ok(())
+    }
+}
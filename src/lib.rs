@@ -1,30 +1,73 @@
 #![allow(clippy::missing_errors_doc)]
 #![allow(clippy::module_name_repetitions)]
 pub mod aligned;
+pub mod any;
+#[cfg(feature = "mmap")]
+pub mod arena;
+#[cfg(feature = "rayon")]
+pub mod batch;
 pub mod borrowed;
+pub mod chunk_ref;
+pub mod chunker;
 pub mod cow;
 pub mod encrypted;
+pub mod envelope;
 pub mod error;
+pub mod lazy;
+pub mod manifest;
 pub mod mbuf;
+#[cfg(feature = "mmap")]
+pub mod mmap;
 pub mod owned;
 pub mod serialized;
+pub mod store;
+pub mod stream;
+#[cfg(test)]
+mod test_utils;
 pub mod typed;
 pub mod utils;
+pub mod verified;
 pub use aligned::AlignedDataChunk;
+pub use any::AnyDataChunk;
+#[cfg(feature = "mmap")]
+pub use arena::MbufArena;
 pub use borrowed::BorrowedDataChunk;
 pub use bytes::Bytes;
+pub use chunk_ref::ChunkRef;
+pub use chunker::CdcChunker;
+pub use chunker::ChunkerConfig;
+pub use chunker::FixedChunker;
 pub use cow::CowDataChunk;
+pub use encrypted::ChunkKey;
 pub use encrypted::EncryptedDataChunk;
+pub use envelope::MasterKey;
+pub use envelope::WrappedChunkRef;
+pub use envelope::WrappedKey;
 pub use error::DataChunkError;
 pub use error::Result;
+pub use lazy::LazyDataChunk;
+pub use manifest::Manifest;
+pub use manifest::ManifestDataChunk;
 pub use mbuf::MbufDataChunk;
+#[cfg(feature = "mmap")]
+pub use mmap::MmapDataChunk;
 pub use owned::OwnedDataChunk;
 pub use ps_hash::Hash;
 pub use ps_mbuf::Mbuf;
+pub use serialized::BorrowedSerializedDataChunk;
+pub use serialized::HashEncoding;
 pub use serialized::SerializedDataChunk;
+pub use store::ChunkStore;
+pub use store::FsChunkStore;
+pub use store::MemoryChunkStore;
+pub use stream::EncryptingWriter;
+pub use typed::FingerprintedDataChunk;
 pub use typed::ToDataChunk;
 pub use typed::ToTypedDataChunk;
+pub use typed::TypeFingerprint;
 pub use typed::TypedDataChunk;
+pub use typed::TypedMutGuard;
+pub use verified::Verified;
 
 use std::sync::Arc;
 
@@ -52,6 +95,18 @@ where
         *self.hash_ref()
     }
 
+    /// Recalculates the hash of [`Self::data_ref`] and checks it against [`Self::hash_ref`].
+    ///
+    /// Fails with [`DataChunkError::HashMismatch`] if they differ.
+    fn verify(&self) -> Result<()> {
+        utils::verify_hash(self.data_ref(), self.hash_ref())
+    }
+
+    /// Serializes and encrypts this chunk.
+    ///
+    /// The serialized bytes are zstd-compressed by [`ps_cypher::encrypt`] before
+    /// encryption, and [`utils::decrypt`] decompresses them transparently,
+    /// so no separate compression step is needed.
     fn encrypt(&self) -> Result<EncryptedDataChunk> {
         self.serialize()?.encrypt()
     }
@@ -105,4 +160,19 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_encryption_compresses_data() -> Result<()> {
+        let original_data = vec![b'A'; 1 << 16];
+
+        let data_chunk = BorrowedDataChunk::from_data(&original_data)?;
+
+        let encrypted_chunk = data_chunk.encrypt()?;
+        let decrypted_chunk = encrypted_chunk.decrypt()?;
+
+        assert!(encrypted_chunk.data_ref().len() < original_data.len() / 16);
+        assert_eq!(decrypted_chunk.data_ref(), original_data);
+
+        Ok(())
+    }
 }
@@ -0,0 +1,102 @@
+use ps_hash::Hash;
+use rkyv::{with::Map, Archive, Deserialize, Serialize};
+
+use crate::{utils::AsHashBytes, ChunkKey, Result};
+
+/// A single child of a [`crate::Manifest`].
+///
+/// Encrypted children record their key as a [`ChunkKey`].
+#[derive(Archive, Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct ManifestEntry {
+    #[rkyv(with = AsHashBytes)]
+    hash: Hash,
+    length: u64,
+    #[rkyv(with = Map<AsHashBytes>)]
+    key: Option<ChunkKey>,
+}
+
+impl ManifestEntry {
+    /// Creates an entry for a child stored as plaintext.
+    ///
+    /// `length` is the length of the child's reassembled bytes.
+    #[must_use]
+    pub fn plain(hash: Hash, length: u64) -> Self {
+        Self {
+            hash,
+            length,
+            key: None,
+        }
+    }
+
+    /// Creates an entry for a child stored as ciphertext.
+    ///
+    /// `hash` is the hash of the ciphertext,
+    /// `length` is the length of the child's reassembled bytes.
+    #[must_use]
+    pub fn encrypted(hash: Hash, key: Hash, length: u64) -> Self {
+        Self::encrypted_with_key(hash, ChunkKey::new(&key), length)
+    }
+
+    /// Creates an entry for a child stored as ciphertext, taking its key as a [`ChunkKey`].
+    #[must_use]
+    pub const fn encrypted_with_key(hash: Hash, key: ChunkKey, length: u64) -> Self {
+        Self {
+            hash,
+            length,
+            key: Some(key),
+        }
+    }
+
+    /// Returns the hash under which the child is stored.
+    #[must_use]
+    pub const fn hash(&self) -> Hash {
+        self.hash
+    }
+
+    /// Returns the length of the child's reassembled bytes.
+    #[must_use]
+    pub const fn length(&self) -> u64 {
+        self.length
+    }
+
+    /// Returns the decryption key, if the child is encrypted.
+    #[cfg(not(feature = "zeroize"))]
+    #[must_use]
+    pub fn key(&self) -> Option<Hash> {
+        self.key.as_ref().map(ChunkKey::get)
+    }
+
+    /// Returns the decryption key, if the child is encrypted.
+    ///
+    /// With the `zeroize` feature, this is the only accessor for the key.
+    #[must_use]
+    pub const fn chunk_key(&self) -> Option<&ChunkKey> {
+        self.key.as_ref()
+    }
+}
+
+impl ArchivedManifestEntry {
+    /// Returns the hash under which the child is stored.
+    pub fn hash(&self) -> Result<Hash> {
+        Ok(Hash::validate(self.hash)?)
+    }
+
+    /// Returns the length of the child's reassembled bytes.
+    #[must_use]
+    pub const fn length(&self) -> u64 {
+        self.length.to_native()
+    }
+
+    /// Returns the decryption key, if the child is encrypted.
+    #[cfg(not(feature = "zeroize"))]
+    pub fn key(&self) -> Result<Option<Hash>> {
+        Ok(self.key.as_ref().map(Hash::validate).transpose()?)
+    }
+
+    /// Returns the decryption key, if the child is encrypted.
+    ///
+    /// With the `zeroize` feature, this is the only accessor for the key.
+    pub fn chunk_key(&self) -> Result<Option<ChunkKey>> {
+        self.key.as_ref().map(ChunkKey::from_bytes).transpose()
+    }
+}
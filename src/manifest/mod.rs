@@ -0,0 +1,344 @@
+mod entry;
+
+use std::io::Write;
+
+use ps_hash::Hash;
+use rkyv::{Archive, Deserialize, Serialize};
+
+use crate::{
+    utils, AlignedDataChunk, DataChunk, DataChunkError, EncryptedDataChunk, Result, TypedDataChunk,
+};
+
+pub use entry::*;
+
+/// The maximum number of entries [`crate::EncryptingWriter`] puts in a single manifest.
+pub const MANIFEST_FANOUT: usize = 1024;
+
+/// The deepest manifest accepted by [`ArchivedManifest::reassemble`].
+///
+/// With [`MANIFEST_FANOUT`] entries per manifest, a tree of this depth has
+/// `1024^7 = 2^70` leaves, more than any `u64` length can describe, so deeper
+/// trees are rejected rather than recursed into.
+pub const MAX_MANIFEST_DEPTH: u32 = 6;
+
+/// A [`TypedDataChunk`] holding an archived [`Manifest`].
+pub type ManifestDataChunk<D = AlignedDataChunk> = TypedDataChunk<D, Manifest>;
+
+/// Describes a large object as an ordered list of child chunks.
+///
+/// The children of a manifest with `depth == 0` hold the object's bytes.
+/// The children of a manifest with `depth == n` are manifests with `depth == n - 1`,
+/// which allows arbitrarily large objects to be described by a tree of small manifests.
+#[derive(Archive, Serialize, Deserialize, Clone, Debug, Default, PartialEq, Eq)]
+pub struct Manifest {
+    depth: u32,
+    entries: Vec<ManifestEntry>,
+}
+
+impl Manifest {
+    #[must_use]
+    pub const fn new() -> Self {
+        Self::with_depth(0)
+    }
+
+    #[must_use]
+    pub const fn with_depth(depth: u32) -> Self {
+        Self {
+            depth,
+            entries: Vec::new(),
+        }
+    }
+
+    #[must_use]
+    pub const fn depth(&self) -> u32 {
+        self.depth
+    }
+
+    #[must_use]
+    pub fn entries(&self) -> &[ManifestEntry] {
+        &self.entries
+    }
+
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns the length of the reassembled object.
+    ///
+    /// Fails with [`DataChunkError::InvalidLayout`] if the lengths overflow a `u64`.
+    pub fn total_length(&self) -> Result<u64> {
+        checked_sum(self.entries.iter().map(ManifestEntry::length))
+    }
+
+    pub fn push_entry(&mut self, entry: ManifestEntry) {
+        self.entries.push(entry);
+    }
+
+    /// Appends a child stored as plaintext.
+    pub fn push<C: DataChunk>(&mut self, chunk: &C) {
+        self.push_entry(ManifestEntry::plain(
+            chunk.hash(),
+            chunk.data_ref().len() as u64,
+        ));
+    }
+
+    /// Appends a child stored as ciphertext.
+    ///
+    /// `length` is the length of the plaintext, which `chunk` does not record.
+    pub fn push_encrypted(&mut self, chunk: &EncryptedDataChunk, length: u64) {
+        self.push_entry(ManifestEntry::encrypted_with_key(
+            chunk.hash(),
+            chunk.chunk_key().clone(),
+            length,
+        ));
+    }
+}
+
+impl ArchivedManifest {
+    #[must_use]
+    pub const fn depth(&self) -> u32 {
+        self.depth.to_native()
+    }
+
+    #[must_use]
+    pub fn entries(&self) -> &[ArchivedManifestEntry] {
+        &self.entries
+    }
+
+    /// Returns the length of the reassembled object.
+    ///
+    /// Fails with [`DataChunkError::InvalidLayout`] if the lengths overflow a `u64`.
+    pub fn total_length(&self) -> Result<u64> {
+        checked_sum(self.entries.iter().map(ArchivedManifestEntry::length))
+    }
+
+    /// Writes the original bytes to `writer`, returning the number of bytes written.
+    ///
+    /// Each child is fetched through `lookup`, its hash is recalculated and
+    /// verified, and encrypted children are decrypted before being written.
+    /// Nested manifests are reassembled recursively; manifests deeper than
+    /// [`MAX_MANIFEST_DEPTH`] are rejected with [`DataChunkError::InvalidLayout`].
+    pub fn reassemble<F, C, W>(&self, mut lookup: F, writer: &mut W) -> Result<u64>
+    where
+        F: FnMut(&Hash) -> Result<C>,
+        C: DataChunk,
+        W: Write,
+    {
+        self.reassemble_with(&mut lookup, writer)
+    }
+
+    fn reassemble_with<F, C, W>(&self, lookup: &mut F, writer: &mut W) -> Result<u64>
+    where
+        F: FnMut(&Hash) -> Result<C>,
+        C: DataChunk,
+        W: Write,
+    {
+        if self.depth() > MAX_MANIFEST_DEPTH {
+            return Err(DataChunkError::InvalidLayout);
+        }
+
+        let mut written: u64 = 0;
+
+        for entry in self.entries() {
+            let hash = entry.hash()?;
+            let chunk = lookup(&hash)?;
+
+            utils::verify_hash(chunk.data_ref(), &hash)?;
+
+            let length = match entry.chunk_key()? {
+                Some(key) => {
+                    let decrypted = key.decrypt(chunk.data_ref())?;
+
+                    self.write_child(decrypted.data_ref(), entry.length(), lookup, writer)?
+                }
+                None => self.write_child(chunk.data_ref(), entry.length(), lookup, writer)?,
+            };
+
+            if length != entry.length() {
+                return Err(DataChunkError::InvalidLayout);
+            }
+
+            written = written
+                .checked_add(length)
+                .ok_or(DataChunkError::InvalidLayout)?;
+        }
+
+        Ok(written)
+    }
+
+    /// Writes a child whose entry records `length` bytes, checking the length before writing.
+    fn write_child<F, C, W>(
+        &self,
+        data: &[u8],
+        length: u64,
+        lookup: &mut F,
+        writer: &mut W,
+    ) -> Result<u64>
+    where
+        F: FnMut(&Hash) -> Result<C>,
+        C: DataChunk,
+        W: Write,
+    {
+        if self.depth() == 0 {
+            if data.len() as u64 != length {
+                return Err(DataChunkError::InvalidLayout);
+            }
+
+            writer.write_all(data)?;
+
+            return Ok(data.len() as u64);
+        }
+
+        // The manifest holds its children's keys, so it is copied into a buffer wiped on drop.
+        let aligned = utils::AlignedPlaintext::copy_from_slice(data);
+
+        let child = AlignedDataChunk::try_bytes_as::<Manifest>(&aligned)?;
+
+        if self.depth().checked_sub(1) != Some(child.depth()) || child.total_length()? != length {
+            return Err(DataChunkError::InvalidLayout);
+        }
+
+        child.reassemble_with(lookup, writer)
+    }
+}
+
+fn checked_sum(mut lengths: impl Iterator<Item = u64>) -> Result<u64> {
+    lengths
+        .try_fold(0u64, u64::checked_add)
+        .ok_or(DataChunkError::InvalidLayout)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::*;
+    use crate::{
+        test_utils::sample, CdcChunker, ChunkerConfig, OwnedDataChunk, ToDataChunk,
+        ToTypedDataChunk,
+    };
+
+    #[test]
+    fn reassembles_plain_and_encrypted_children() -> Result<()> {
+        let data = sample(200_000);
+        let config = ChunkerConfig::new(1024, 4096, 16384)?;
+        let mut store = HashMap::new();
+        let mut manifest = Manifest::new();
+
+        for (index, chunk) in CdcChunker::with_config(&data[..], config).enumerate() {
+            let chunk = chunk?;
+
+            if index % 2 == 0 {
+                manifest.push(&chunk);
+                store.insert(chunk.hash(), chunk);
+            } else {
+                let encrypted = chunk.encrypt()?;
+
+                manifest.push_encrypted(&encrypted, chunk.data_ref().len() as u64);
+                store.insert(encrypted.hash(), encrypted.into_owned());
+            }
+        }
+
+        let manifest: ManifestDataChunk = manifest.to_typed_datachunk()?;
+        let mut output = Vec::new();
+
+        let lookup = |hash: &Hash| {
+            store
+                .get(hash)
+                .cloned()
+                .ok_or(DataChunkError::InvalidLayout)
+        };
+        let written = manifest.reassemble(lookup, &mut output)?;
+
+        assert_eq!(written, data.len() as u64);
+        assert_eq!(manifest.total_length()?, data.len() as u64);
+        assert_eq!(output, data);
+
+        Ok(())
+    }
+
+    #[test]
+    fn reassemble_rejects_tampered_child() -> Result<()> {
+        let chunk = OwnedDataChunk::from_data(b"hello world".to_vec())?;
+        let mut manifest = Manifest::new();
+
+        manifest.push(&chunk);
+
+        let manifest: ManifestDataChunk = manifest.to_typed_datachunk()?;
+        let tampered =
+            OwnedDataChunk::from_parts_unchecked(b"hello there".to_vec().into(), chunk.hash());
+
+        let result = manifest.reassemble(|_| Ok(tampered.clone()), &mut Vec::new());
+
+        assert!(matches!(result, Err(DataChunkError::HashMismatch { .. })));
+
+        Ok(())
+    }
+
+    #[test]
+    fn rejects_overflowing_and_mismatched_lengths() -> Result<()> {
+        let chunk = OwnedDataChunk::from_data(b"hello world".to_vec())?;
+        let mut manifest = Manifest::new();
+
+        manifest.push_entry(ManifestEntry::plain(chunk.hash(), u64::MAX));
+        manifest.push_entry(ManifestEntry::plain(chunk.hash(), 1));
+
+        assert!(matches!(
+            manifest.total_length(),
+            Err(DataChunkError::InvalidLayout)
+        ));
+
+        let typed: ManifestDataChunk = manifest.to_typed_datachunk()?;
+
+        assert!(matches!(
+            typed.total_length(),
+            Err(DataChunkError::InvalidLayout)
+        ));
+
+        let mut written = Vec::new();
+        let result = typed.reassemble(|_| Ok(chunk.clone()), &mut written);
+
+        assert!(matches!(result, Err(DataChunkError::InvalidLayout)));
+        assert!(written.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn rejects_invalid_depths() -> Result<()> {
+        let mut store = HashMap::new();
+        let mut child = Manifest::with_depth(u32::MAX);
+
+        child.push(&OwnedDataChunk::from_data(b"hello world".to_vec())?);
+
+        let child = child.to_datachunk()?.into_owned();
+        let mut parent = Manifest::with_depth(1);
+
+        parent.push_entry(ManifestEntry::plain(child.hash(), 11));
+        store.insert(child.hash(), child);
+
+        let parent: ManifestDataChunk = parent.to_typed_datachunk()?;
+        let lookup = |hash: &Hash| store.get(hash).cloned().ok_or(DataChunkError::NotFound);
+
+        assert!(matches!(
+            parent.reassemble(lookup, &mut Vec::new()),
+            Err(DataChunkError::InvalidLayout)
+        ));
+
+        let deep: ManifestDataChunk =
+            Manifest::with_depth(MAX_MANIFEST_DEPTH + 1).to_typed_datachunk()?;
+
+        assert!(matches!(
+            deep.reassemble(lookup, &mut Vec::new()),
+            Err(DataChunkError::InvalidLayout)
+        ));
+
+        Ok(())
+    }
+}
@@ -5,7 +5,7 @@ use std::sync::Arc;
 use ps_hash::Hash;
 use ps_mbuf::Mbuf;
 
-use crate::DataChunk;
+use crate::{DataChunk, DataChunkError, Result};
 
 #[derive(Clone, Copy)]
 pub struct MbufDataChunk<'lt> {
@@ -18,6 +18,65 @@ impl<'lt> From<&'lt Mbuf<'lt, Hash, u8>> for MbufDataChunk<'lt> {
     }
 }
 
+impl<'lt> MbufDataChunk<'lt> {
+    /// Required alignment of a memory region holding an [`Mbuf`] chunk.
+    pub const ALIGNMENT: usize = align_of::<Mbuf<'static, Hash, u8>>();
+
+    /// Size of the header preceding an [`Mbuf`] chunk's data.
+    pub const HEADER_SIZE: usize = size_of::<Mbuf<'static, Hash, u8>>();
+
+    /// Returns the number of bytes needed to hold a chunk of `data_length` bytes.
+    #[must_use]
+    pub const fn required_size(data_length: usize) -> usize {
+        Self::HEADER_SIZE + data_length
+    }
+
+    /// Writes `chunk`'s hash and data into `region` as an [`Mbuf`], returning a chunk pointing at it.
+    ///
+    /// `region` may be any writable memory, such as a shared memory-mapped file.
+    /// It must be aligned to [`Self::ALIGNMENT`] and at least
+    /// [`Self::required_size`] bytes long, otherwise [`DataChunkError::InvalidRegion`] is returned.
+    pub fn write_into<C: DataChunk>(chunk: &C, region: &'lt mut [u8]) -> Result<Self> {
+        let data = chunk.data_ref();
+
+        Self::check_region(region, data.len())?;
+
+        // SAFETY: `region` is aligned for, and large enough to hold, an `Mbuf` of `data.len()`
+        // bytes, and is borrowed exclusively for `'lt`, after which it is only read through `inner`.
+        let inner = unsafe { Mbuf::write_to_ptr(region.as_mut_ptr(), chunk.hash(), data) };
+
+        Ok(Self { inner })
+    }
+
+    /// Interprets `region` as an [`Mbuf`] chunk previously written by [`Self::write_into`].
+    ///
+    /// The alignment and bounds of `region` are checked, but the hash is not;
+    /// call [`DataChunk::verify`] if `region` is untrusted.
+    pub fn from_region(region: &'lt [u8]) -> Result<Self> {
+        Self::check_region(region, 0)?;
+
+        // SAFETY: `region` is aligned and holds at least an `Mbuf` header; every bit pattern
+        // is a valid `Hash` and `usize`.
+        let inner = unsafe { Mbuf::<Hash, u8>::at_ptr(region.as_ptr()) };
+
+        Self::check_region(region, inner.len())?;
+
+        Ok(Self { inner })
+    }
+
+    fn check_region(region: &[u8], data_length: usize) -> Result<()> {
+        let required = Self::HEADER_SIZE
+            .checked_add(data_length)
+            .ok_or(DataChunkError::InvalidRegion)?;
+
+        if region.as_ptr().align_offset(Self::ALIGNMENT) != 0 || region.len() < required {
+            return Err(DataChunkError::InvalidRegion);
+        }
+
+        Ok(())
+    }
+}
+
 impl DataChunk for MbufDataChunk<'_> {
     fn data_ref(&self) -> &[u8] {
         self.inner
@@ -32,3 +91,59 @@ impl DataChunk for MbufDataChunk<'_> {
         crate::OwnedDataChunk::from_data_and_hash_unchecked(Arc::from(self.data_ref()), self.hash())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use rkyv::util::AlignedVec;
+
+    use super::*;
+    use crate::BorrowedDataChunk;
+
+    #[test]
+    fn write_into_region_roundtrip() -> Result<()> {
+        let chunk = BorrowedDataChunk::from_data(b"hello world")?;
+        let size = MbufDataChunk::required_size(chunk.data_ref().len());
+
+        let mut region: AlignedVec = AlignedVec::new();
+        region.resize(size, 0);
+
+        let written = MbufDataChunk::write_into(&chunk, &mut region)?;
+
+        assert_eq!(written.data_ref(), b"hello world");
+        assert_eq!(written.hash(), chunk.hash());
+
+        let read = MbufDataChunk::from_region(&region)?;
+
+        assert_eq!(read.data_ref(), b"hello world");
+        read.verify()?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn write_into_rejects_bad_regions() -> Result<()> {
+        let chunk = BorrowedDataChunk::from_data(b"hello world")?;
+        let size = MbufDataChunk::required_size(chunk.data_ref().len());
+
+        let mut region: AlignedVec = AlignedVec::new();
+        region.resize(size + 1, 0);
+
+        assert!(matches!(
+            MbufDataChunk::write_into(&chunk, &mut region[..size - 1]),
+            Err(DataChunkError::InvalidRegion)
+        ));
+        assert!(matches!(
+            MbufDataChunk::write_into(&chunk, &mut region[1..]),
+            Err(DataChunkError::InvalidRegion)
+        ));
+
+        MbufDataChunk::write_into(&chunk, &mut region[..size])?;
+
+        assert!(matches!(
+            MbufDataChunk::from_region(&region[..size - 1]),
+            Err(DataChunkError::InvalidRegion)
+        ));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,162 @@
+use std::{fs::File, path::Path};
+
+use bytes::Bytes;
+use memmap2::{Mmap, MmapOptions};
+use ps_hash::Hash;
+
+use crate::{DataChunk, OwnedDataChunk, Result};
+
+/// A chunk of data backed by a read-only memory map of a file.
+///
+/// The mapping starts at a page boundary when mapping a whole file, so it can be
+/// passed to [`crate::TypedDataChunk`] for zero-copy archived access.
+#[derive(Debug)]
+pub struct MmapDataChunk {
+    mmap: Mmap,
+    hash: Hash,
+}
+
+impl MmapDataChunk {
+    /// Maps the file at `path` and hashes its contents.
+    ///
+    /// # Safety
+    ///
+    /// The file must not be modified or truncated while the returned chunk,
+    /// or anything created from it via [`DataChunk::into_bytes`] or
+    /// [`DataChunk::into_owned`], is alive. [`DataChunk`] requires its bytes to
+    /// be stable; modifying the file violates this and is undefined behaviour.
+    pub unsafe fn open<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let file = File::open(path)?;
+
+        // SAFETY: upheld by the caller.
+        unsafe { Self::from_file(&file) }
+    }
+
+    /// Maps all of `file` and hashes its contents.
+    ///
+    /// # Safety
+    ///
+    /// See [`MmapDataChunk::open`].
+    pub unsafe fn from_file(file: &File) -> Result<Self> {
+        // SAFETY: upheld by the caller.
+        let mmap = unsafe { Mmap::map(file)? };
+
+        Self::from_mmap(mmap)
+    }
+
+    /// Maps `length` bytes of `file`, starting at `offset`, and hashes them.
+    ///
+    /// # Safety
+    ///
+    /// See [`MmapDataChunk::open`]; the requirement applies to the mapped range.
+    pub unsafe fn from_file_range(file: &File, offset: u64, length: usize) -> Result<Self> {
+        // SAFETY: upheld by the caller.
+        let mmap = unsafe { MmapOptions::new().offset(offset).len(length).map(file)? };
+
+        Self::from_mmap(mmap)
+    }
+
+    /// Hashes the contents of an existing mapping.
+    pub fn from_mmap(mmap: Mmap) -> Result<Self> {
+        let hash = ps_hash::hash(&mmap)?;
+
+        Ok(Self { mmap, hash })
+    }
+
+    /// Wraps an existing mapping without hashing it.
+    ///
+    /// # Safety
+    ///
+    /// - `hash` must be the hash of `mmap`'s contents
+    /// - use `from_mmap()` if you cannot ensure this
+    #[must_use]
+    pub const fn from_parts_unchecked(mmap: Mmap, hash: Hash) -> Self {
+        Self { mmap, hash }
+    }
+
+    #[must_use]
+    pub fn into_mmap(self) -> Mmap {
+        self.mmap
+    }
+}
+
+impl DataChunk for MmapDataChunk {
+    fn data_ref(&self) -> &[u8] {
+        &self.mmap
+    }
+
+    fn hash_ref(&self) -> &Hash {
+        &self.hash
+    }
+
+    /// Transforms this [`DataChunk`] into [`Bytes`] without copying.
+    ///
+    /// The mapping stays alive until the last clone of the returned [`Bytes`] is dropped.
+    fn into_bytes(self) -> Bytes {
+        Bytes::from_owner(self.mmap)
+    }
+
+    /// Transforms this chunk into an [`OwnedDataChunk`] without copying.
+    fn into_owned(self) -> OwnedDataChunk {
+        let Self { mmap, hash } = self;
+
+        OwnedDataChunk::from_data_and_hash_unchecked(mmap, hash)
+    }
+}
+
+impl AsRef<[u8]> for MmapDataChunk {
+    fn as_ref(&self) -> &[u8] {
+        &self.mmap
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::*;
+    use crate::{test_utils::TempPath, ToDataChunk};
+
+    #[test]
+    fn mmap_whole_file_and_range() -> Result<()> {
+        let file = TempPath::new("mmap-range");
+
+        fs::write(&file.0, b"hello world")?;
+
+        // SAFETY: the file is private to this test and not modified.
+        let chunk = unsafe { MmapDataChunk::open(&file.0)? };
+
+        assert_eq!(chunk.data_ref(), b"hello world");
+        assert_eq!(chunk.hash(), ps_hash::hash(b"hello world")?);
+
+        // SAFETY: as above.
+        let range = unsafe { MmapDataChunk::from_file_range(&File::open(&file.0)?, 6, 5)? };
+
+        assert_eq!(range.data_ref(), b"world");
+        assert_eq!(range.hash(), ps_hash::hash(b"world")?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn mmap_into_owned_and_typed() -> Result<()> {
+        let value: u64 = 0x0123_4567_89ab_cdef;
+        let file = TempPath::new("mmap-typed");
+
+        fs::write(&file.0, value.to_datachunk()?.data_ref())?;
+
+        // SAFETY: the file is private to this test and not modified.
+        let chunk = unsafe { MmapDataChunk::open(&file.0)? };
+        let pointer = chunk.data_ref().as_ptr();
+
+        let typed = chunk.try_as::<u64>()?;
+
+        assert_eq!(*typed, value);
+
+        let owned = typed.into_owned();
+
+        assert_eq!(owned.data_ref().as_ptr(), pointer);
+
+        Ok(())
+    }
+}
@@ -1,3 +1,6 @@
 mod as_ref;
 mod deref;
 mod from;
+mod rkyv;
+#[cfg(feature = "serde")]
+mod serde;
@@ -0,0 +1,49 @@
+use rancor::{Fallible, Source};
+use rkyv::Deserialize;
+
+use crate::{owned::ArchivedOwnedDataChunk, utils, OwnedDataChunk};
+
+/// Deserializes an [`OwnedDataChunk`], failing if its hash does not match its data.
+impl<D> Deserialize<OwnedDataChunk, D> for ArchivedOwnedDataChunk
+where
+    D: Fallible + ?Sized,
+    D::Error: Source,
+{
+    fn deserialize(&self, _: &mut D) -> Result<OwnedDataChunk, D::Error> {
+        let hash = self.hash().map_err(D::Error::new)?;
+
+        utils::verify_hash(self.data_ref(), &hash).map_err(D::Error::new)?;
+
+        Ok(OwnedDataChunk::from_parts_unchecked(
+            self.data_ref().to_vec().into(),
+            hash,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{AlignedDataChunk, DataChunk, DataChunkError, OwnedDataChunk, Result};
+
+    #[test]
+    fn rkyv_rejects_mismatched_hash() -> Result<()> {
+        let chunk = OwnedDataChunk::from_data(b"hello world".to_vec())?;
+        let mut archived = AlignedDataChunk::try_from(&chunk)?.data_ref().to_vec();
+
+        let restored: OwnedDataChunk = rkyv::from_bytes::<_, rancor::Error>(&archived)
+            .map_err(DataChunkError::Deserialization)?;
+
+        assert_eq!(restored, chunk);
+
+        let position = archived
+            .windows(11)
+            .position(|window| window == b"hello world")
+            .ok_or(DataChunkError::InvalidArchive)?;
+
+        archived[position] = b'j';
+
+        assert!(rkyv::from_bytes::<OwnedDataChunk, rancor::Error>(&archived).is_err());
+
+        Ok(())
+    }
+}
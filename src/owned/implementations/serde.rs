@@ -0,0 +1,56 @@
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{utils::serde_chunk, OwnedDataChunk};
+
+impl Serialize for OwnedDataChunk {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde_chunk::serialize(self, serializer)
+    }
+}
+
+/// Deserializes an [`OwnedDataChunk`], failing if its hash does not match its data.
+impl<'de> Deserialize<'de> for OwnedDataChunk {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let (data, hash) = serde_chunk::deserialize(deserializer)?;
+
+        Ok(Self::from_parts_unchecked(data, hash))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{BorrowedDataChunk, OwnedDataChunk, Result};
+
+    #[test]
+    fn serde_roundtrip() -> Result<()> {
+        let chunk = OwnedDataChunk::from_data(b"hello world".to_vec())?;
+
+        let json = serde_json::to_string(&chunk).map_err(std::io::Error::other)?;
+
+        assert_eq!(
+            json,
+            serde_json::to_string(&BorrowedDataChunk::from_data(b"hello world")?)
+                .map_err(std::io::Error::other)?
+        );
+
+        let parsed: OwnedDataChunk = serde_json::from_str(&json).map_err(std::io::Error::other)?;
+
+        assert_eq!(parsed, chunk);
+
+        Ok(())
+    }
+
+    #[test]
+    fn serde_rejects_mismatched_hash() -> Result<()> {
+        let chunk = OwnedDataChunk::from_data(b"hello world".to_vec())?;
+        let other = OwnedDataChunk::from_data(b"hello there".to_vec())?;
+
+        let json = serde_json::to_string(&chunk)
+            .map_err(std::io::Error::other)?
+            .replace(&chunk.hash().to_string(), &other.hash().to_string());
+
+        assert!(serde_json::from_str::<OwnedDataChunk>(&json).is_err());
+
+        Ok(())
+    }
+}
@@ -1,13 +1,20 @@
 mod implementations;
 
+use crate::utils::AsHashBytes;
+use crate::BorrowedDataChunk;
 use crate::DataChunk;
 use crate::Result;
 use bytes::Bytes;
 use ps_hash::Hash;
 
-#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, rkyv::Archive, rkyv::Serialize)]
 /// represents an owned chunk of data
+///
+/// Archiving with rkyv stores the hash and the data inline, so chunks can be
+/// nested inside archived structures; see [`ArchivedOwnedDataChunk::as_borrowed`].
+/// Deserializing recalculates the hash and fails if it does not match the data.
 pub struct OwnedDataChunk {
+    #[rkyv(with = AsHashBytes)]
     hash: Hash,
     data: Bytes,
 }
@@ -69,6 +76,30 @@ impl OwnedDataChunk {
     }
 }
 
+impl ArchivedOwnedDataChunk {
+    /// Returns the archived chunk's bytes, without copying.
+    #[must_use]
+    pub fn data_ref(&self) -> &[u8] {
+        &self.data
+    }
+
+    /// Validates and returns the archived hash.
+    pub fn hash(&self) -> Result<Hash> {
+        Ok(Hash::validate(self.hash)?)
+    }
+
+    /// Returns a [`BorrowedDataChunk`] view of the archived chunk, without copying.
+    ///
+    /// The archived hash is validated, but not compared to the data;
+    /// call [`DataChunk::verify`] on the result if the archive is untrusted.
+    pub fn as_borrowed(&self) -> Result<BorrowedDataChunk<'_>> {
+        Ok(BorrowedDataChunk::from_parts_unchecked(
+            self.data_ref(),
+            self.hash()?,
+        ))
+    }
+}
+
 impl DataChunk for OwnedDataChunk {
     fn data_ref(&self) -> &[u8] {
         self.data_ref()
@@ -87,3 +118,45 @@ impl DataChunk for OwnedDataChunk {
         self
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use rkyv::{Archive, Deserialize, Serialize};
+
+    use super::*;
+    use crate::{DataChunkError, ToTypedDataChunk};
+
+    #[derive(Archive, Serialize, Deserialize)]
+    struct Record {
+        name: String,
+        chunk: OwnedDataChunk,
+    }
+
+    #[test]
+    fn archived_chunk_nests_without_copying() -> Result<()> {
+        let chunk = OwnedDataChunk::from_data(b"hello world".to_vec())?;
+        let record = Record {
+            name: "greeting".to_string(),
+            chunk: chunk.clone(),
+        };
+
+        let typed = record.to_typed_datachunk()?;
+        let borrowed = typed.chunk.as_borrowed()?;
+
+        assert_eq!(typed.name, "greeting");
+        assert_eq!(borrowed.data_ref(), b"hello world");
+        assert_eq!(borrowed.hash(), chunk.hash());
+        assert!(typed
+            .data_ref()
+            .as_ptr_range()
+            .contains(&borrowed.data_ref().as_ptr()));
+        borrowed.verify()?;
+
+        let restored: Record = rkyv::from_bytes::<_, rancor::Error>(typed.data_ref())
+            .map_err(|_| DataChunkError::InvalidArchive)?;
+
+        assert_eq!(restored.chunk, chunk);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,184 @@
+use ps_buffer::Buffer;
+use ps_hash::Hash;
+
+use super::{HashEncoding, Layout};
+use crate::{BorrowedDataChunk, DataChunk, Result, SerializedDataChunk};
+
+/// A zero-copy view of a serialized chunk, such as one read from a memory map.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct BorrowedSerializedDataChunk<'lt> {
+    bytes: &'lt [u8],
+    hash: Hash,
+    offset: usize,
+}
+
+impl<'lt> BorrowedSerializedDataChunk<'lt> {
+    pub(crate) const fn from_parts_unchecked(bytes: &'lt [u8], hash: Hash, offset: usize) -> Self {
+        Self {
+            bytes,
+            hash,
+            offset,
+        }
+    }
+
+    /// Validates `bytes` in place, returning a view over them.
+    ///
+    /// The layout is parsed exactly as in [`SerializedDataChunk::from_serialized_buffer`],
+    /// and the hash is recalculated and verified.
+    pub fn parse(bytes: &'lt [u8]) -> Result<Self> {
+        let (layout, hash) = Layout::verify(bytes)?;
+
+        Ok(Self::from_parts_unchecked(bytes, hash, layout.offset()))
+    }
+
+    /// Returns the serialized bytes this view was parsed from.
+    #[must_use]
+    pub const fn serialized_bytes(&self) -> &'lt [u8] {
+        self.bytes
+    }
+
+    /// Returns the chunk's data, borrowed for the full lifetime of the underlying bytes.
+    #[must_use]
+    pub fn data(&self) -> &'lt [u8] {
+        &self.bytes[self.offset..]
+    }
+
+    /// Returns the format version of the serialized bytes.
+    #[must_use]
+    pub fn version(&self) -> u8 {
+        Layout::version_of(self.bytes)
+    }
+
+    /// Returns how the serialized bytes store the hash.
+    #[must_use]
+    pub fn hash_encoding(&self) -> HashEncoding {
+        Layout::hash_encoding_of(self.bytes)
+    }
+
+    /// Copies the serialized bytes into an owned [`SerializedDataChunk`].
+    pub fn to_serialized(&self) -> Result<SerializedDataChunk> {
+        Ok(SerializedDataChunk::from_serialized_parts_unchecked(
+            Buffer::from_slice(self.bytes)?,
+            self.hash,
+            self.offset,
+        ))
+    }
+}
+
+impl DataChunk for BorrowedSerializedDataChunk<'_> {
+    fn data_ref(&self) -> &[u8] {
+        self.data()
+    }
+
+    fn hash_ref(&self) -> &Hash {
+        &self.hash
+    }
+
+    fn borrow(&self) -> BorrowedDataChunk<'_> {
+        BorrowedDataChunk::from_parts_unchecked(self.data(), self.hash)
+    }
+
+    /// Copies the serialized bytes, preserving their layout.
+    fn serialize(&self) -> Result<SerializedDataChunk> {
+        self.to_serialized()
+    }
+}
+
+impl<'lt> From<BorrowedSerializedDataChunk<'lt>> for BorrowedDataChunk<'lt> {
+    fn from(value: BorrowedSerializedDataChunk<'lt>) -> Self {
+        Self::from_parts_unchecked(value.data(), value.hash)
+    }
+}
+
+impl TryFrom<BorrowedSerializedDataChunk<'_>> for SerializedDataChunk {
+    type Error = crate::DataChunkError;
+
+    fn try_from(value: BorrowedSerializedDataChunk<'_>) -> Result<Self> {
+        value.to_serialized()
+    }
+}
+
+impl AsRef<[u8]> for BorrowedSerializedDataChunk<'_> {
+    fn as_ref(&self) -> &[u8] {
+        self.data()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ps_hash::HASH_SIZE;
+
+    use crate::{serialized::HEADER_SIZE, DataChunkError, HashEncoding};
+
+    #[test]
+    fn parse_borrows_without_copying() -> Result<()> {
+        for encoding in [HashEncoding::Text, HashEncoding::Binary] {
+            let owned = SerializedDataChunk::from_data_with_encoding(b"hello world", encoding)?;
+            let bytes = owned.serialized_bytes();
+
+            let borrowed = SerializedDataChunk::parse(bytes)?;
+
+            assert_eq!(borrowed.data_ref(), b"hello world");
+            assert_eq!(borrowed.hash(), owned.hash());
+            assert_eq!(borrowed.hash_encoding(), encoding);
+            assert!(bytes.as_ptr_range().contains(&borrowed.data_ref().as_ptr()));
+
+            let copied = borrowed.to_serialized()?;
+
+            assert_eq!(copied.serialized_bytes(), bytes);
+            assert_eq!(copied, owned);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn parse_rejects_tampered_data() -> Result<()> {
+        let owned = SerializedDataChunk::from_data(b"hello world")?;
+        let mut bytes = owned.serialized_bytes().to_vec();
+        let last = bytes.len() - 1;
+
+        bytes[last] ^= 1;
+
+        assert!(matches!(
+            BorrowedSerializedDataChunk::parse(&bytes),
+            Err(DataChunkError::HashMismatch { .. })
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn parse_reports_stored_hash_uncorrected() -> Result<()> {
+        let owned = SerializedDataChunk::from_data(b"hello world")?;
+        let other = SerializedDataChunk::from_data(b"hello there")?;
+        let hash_range = HEADER_SIZE..HEADER_SIZE + HASH_SIZE;
+
+        let mut swapped = owned.serialized_bytes().to_vec();
+
+        swapped[hash_range.clone()].copy_from_slice(&other.serialized_bytes()[hash_range.clone()]);
+
+        let Err(DataChunkError::HashMismatch { expected, actual }) =
+            BorrowedSerializedDataChunk::parse(&swapped)
+        else {
+            panic!("expected a hash mismatch");
+        };
+
+        assert_eq!(expected, other.hash());
+        assert_eq!(actual, owned.hash());
+
+        for corruption in [1, HASH_SIZE] {
+            let mut corrupt = owned.serialized_bytes().to_vec();
+
+            corrupt[hash_range.start..hash_range.start + corruption].fill(b'!');
+
+            assert!(matches!(
+                BorrowedSerializedDataChunk::parse(&corrupt),
+                Err(DataChunkError::CorruptHash)
+            ));
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,221 @@
+use std::ops::Range;
+
+use ps_hash::{Hash, HASH_SIZE, HASH_SIZE_BIN};
+
+use crate::{utils::hash_to_bytes, DataChunkError, Result};
+
+/// Marks the start of a versioned serialized chunk.
+///
+/// The first byte is not valid base64, so it never collides with the legacy layout.
+pub const MAGIC: [u8; 4] = [0x89, b'P', b'D', b'C'];
+
+/// The format version written by [`crate::SerializedDataChunk::from_parts_unchecked`].
+pub const FORMAT_VERSION: u8 = 1;
+
+/// Size of the versioned header preceding the hash.
+///
+/// `magic: [u8; 4]`, `version: u8`, `flags: u8`, `reserved: [u8; 2]`, `payload_length: u64le`
+pub const HEADER_SIZE: usize = 16;
+
+/// Set if the hash is stored in its raw binary form rather than as text.
+pub const FLAG_BINARY_HASH: u8 = 0x01;
+
+/// Flags understood by this version of the crate.
+const KNOWN_FLAGS: u8 = FLAG_BINARY_HASH;
+
+/// Selects how a serialized chunk stores its hash.
+#[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum HashEncoding {
+    /// The 64-byte base64 representation, as produced by [`Hash::to_string`].
+    #[default]
+    Text,
+    /// The 48-byte binary representation, as produced by [`hash_to_bytes`].
+    Binary,
+}
+
+impl HashEncoding {
+    #[must_use]
+    pub const fn size(self) -> usize {
+        match self {
+            Self::Text => HASH_SIZE,
+            Self::Binary => HASH_SIZE_BIN,
+        }
+    }
+
+    #[must_use]
+    pub const fn flags(self) -> u8 {
+        match self {
+            Self::Text => 0,
+            Self::Binary => FLAG_BINARY_HASH,
+        }
+    }
+
+    #[must_use]
+    pub const fn from_flags(flags: u8) -> Self {
+        if flags & FLAG_BINARY_HASH == 0 {
+            Self::Text
+        } else {
+            Self::Binary
+        }
+    }
+
+    /// Returns `true` if `stored` is `hash` in this encoding.
+    #[must_use]
+    pub fn matches(self, stored: &[u8], hash: &Hash) -> bool {
+        match self {
+            Self::Text => stored == hash.to_string().as_bytes(),
+            Self::Binary => stored == hash_to_bytes(hash),
+        }
+    }
+}
+
+/// Describes where the hash and data of a serialized chunk are located.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Layout {
+    version: u8,
+    flags: u8,
+    hash: Range<usize>,
+    offset: usize,
+}
+
+impl Layout {
+    /// Parses the header of a serialized chunk, without verifying its hash.
+    ///
+    /// Buffers not starting with [`MAGIC`] are interpreted as the legacy
+    /// version 0 layout, which is the text hash directly followed by the data.
+    pub fn parse(bytes: &[u8]) -> Result<Self> {
+        if !bytes.starts_with(&MAGIC) {
+            return Self::parse_legacy(bytes);
+        }
+
+        let header = bytes
+            .get(..HEADER_SIZE)
+            .ok_or(DataChunkError::InvalidLayout)?;
+
+        let version = header[4];
+        let flags = header[5];
+
+        if version != FORMAT_VERSION {
+            return Err(DataChunkError::UnsupportedVersion(version));
+        }
+
+        if flags & !KNOWN_FLAGS != 0 {
+            return Err(DataChunkError::UnsupportedFlags(flags));
+        }
+
+        let payload_length = u64::from_le_bytes(header[8..16].try_into()?);
+        let offset = HEADER_SIZE + HashEncoding::from_flags(flags).size();
+
+        if bytes.len() < offset || (bytes.len() - offset) as u64 != payload_length {
+            return Err(DataChunkError::InvalidLayout);
+        }
+
+        Ok(Self {
+            version,
+            flags,
+            hash: HEADER_SIZE..offset,
+            offset,
+        })
+    }
+
+    /// Parses a serialized chunk, then recalculates and verifies its hash.
+    ///
+    /// Fails with [`DataChunkError::HashMismatch`] if the stored hash is
+    /// well-formed but differs from the data's, and with
+    /// [`DataChunkError::CorruptHash`] if the stored hash is not a valid
+    /// encoding of any hash.
+    pub fn verify(bytes: &[u8]) -> Result<(Self, Hash)> {
+        let layout = Self::parse(bytes)?;
+        let hash = ps_hash::hash(&bytes[layout.offset..])?;
+        let encoding = layout.hash_encoding();
+        let stored = &bytes[layout.hash.clone()];
+
+        if !encoding.matches(stored, &hash) {
+            return Err(match Hash::validate(stored) {
+                Ok(expected) if encoding.matches(stored, &expected) => {
+                    DataChunkError::HashMismatch {
+                        expected,
+                        actual: hash,
+                    }
+                }
+                _ => DataChunkError::CorruptHash,
+            });
+        }
+
+        Ok((layout, hash))
+    }
+
+    /// Returns the format version of `bytes`, which should have been parsed successfully.
+    ///
+    /// The legacy layout, which has no header, reports version `0`,
+    /// as does a header truncated before its version byte.
+    #[must_use]
+    pub fn version_of(bytes: &[u8]) -> u8 {
+        if bytes.starts_with(&MAGIC) {
+            bytes.get(4).copied().unwrap_or(0)
+        } else {
+            0
+        }
+    }
+
+    /// Returns how `bytes`, which should have been parsed successfully, store their hash.
+    #[must_use]
+    pub fn hash_encoding_of(bytes: &[u8]) -> HashEncoding {
+        match Self::version_of(bytes) {
+            0 => HashEncoding::Text,
+            _ => HashEncoding::from_flags(bytes.get(5).copied().unwrap_or(0)),
+        }
+    }
+
+    const fn parse_legacy(bytes: &[u8]) -> Result<Self> {
+        if bytes.len() < HASH_SIZE {
+            return Err(DataChunkError::InvalidLayout);
+        }
+
+        Ok(Self {
+            version: 0,
+            flags: 0,
+            hash: 0..HASH_SIZE,
+            offset: HASH_SIZE,
+        })
+    }
+
+    #[must_use]
+    pub const fn version(&self) -> u8 {
+        self.version
+    }
+
+    #[must_use]
+    pub const fn flags(&self) -> u8 {
+        self.flags
+    }
+
+    /// Returns the range of the serialized bytes holding the stored hash.
+    #[must_use]
+    pub fn hash_range(&self) -> Range<usize> {
+        self.hash.clone()
+    }
+
+    /// Returns the offset at which the data begins.
+    #[must_use]
+    pub const fn offset(&self) -> usize {
+        self.offset
+    }
+
+    #[must_use]
+    pub const fn hash_encoding(&self) -> HashEncoding {
+        HashEncoding::from_flags(self.flags)
+    }
+
+    /// Writes the header for a payload of `payload_length` bytes.
+    pub(crate) fn header(flags: u8, payload_length: usize) -> [u8; HEADER_SIZE] {
+        let mut header = [0u8; HEADER_SIZE];
+
+        header[..4].copy_from_slice(&MAGIC);
+        header[4] = FORMAT_VERSION;
+        header[5] = flags;
+        header[8..].copy_from_slice(&(payload_length as u64).to_le_bytes());
+
+        header
+    }
+}
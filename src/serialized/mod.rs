@@ -1,26 +1,43 @@
+mod borrowed;
+mod layout;
+#[cfg(feature = "serde")]
+mod serde;
+
 use std::ops::Deref;
 
 use bytes::Bytes;
-use ps_buffer::{Buffer, SharedBuffer};
+use ps_buffer::Buffer;
 use ps_hash::{hash, Hash, HASH_SIZE};
 
-use crate::{DataChunk, DataChunkError, EncryptedDataChunk, Result};
+use crate::{utils::hash_to_bytes, DataChunk, EncryptedDataChunk, Result};
+
+pub use borrowed::*;
+pub use layout::*;
 
+/// A chunk laid out as a header, its hash and its data, in a single buffer.
+///
+/// Chunks are written in version [`FORMAT_VERSION`] of the format; see [`Layout`].
+/// They are still encrypted in the legacy layout, so ciphertexts and keys match
+/// those produced before the format was versioned.
+///
+/// With the `zeroize` feature, the buffer is overwritten with zeroes when dropped,
+/// so plaintext returned by [`crate::utils::decrypt`] does not linger in memory.
 #[derive(Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
 pub struct SerializedDataChunk {
     buffer: Buffer,
     hash: Hash,
+    offset: usize,
 }
 
 impl SerializedDataChunk {
     #[must_use]
     pub const fn data_length(&self) -> usize {
-        self.buffer.len().saturating_sub(HASH_SIZE)
+        self.buffer.len().saturating_sub(self.offset)
     }
 
     #[must_use]
     pub const fn is_empty(&self) -> bool {
-        self.buffer.len() <= HASH_SIZE
+        self.buffer.len() <= self.offset
     }
 
     /// # Safety
@@ -31,18 +48,47 @@ impl SerializedDataChunk {
     ///
     /// Call only if `hash` is surely known.
     pub fn from_parts_unchecked<D>(data: D, hash: Hash) -> Result<Self>
+    where
+        D: AsRef<[u8]>,
+    {
+        Self::from_parts_unchecked_with_encoding(data, hash, HashEncoding::Text)
+    }
+
+    /// # Safety
+    ///
+    /// Called guarantees that `hash` is `hash(data)`
+    ///
+    /// This method does **NOT** verify `hash`!
+    ///
+    /// Call only if `hash` is surely known.
+    pub fn from_parts_unchecked_with_encoding<D>(
+        data: D,
+        hash: Hash,
+        encoding: HashEncoding,
+    ) -> Result<Self>
     where
         D: AsRef<[u8]>,
     {
         let data = data.as_ref();
-        let buffer_length = HASH_SIZE + data.len();
+        let offset = HEADER_SIZE + encoding.size();
+        let buffer_length = offset + data.len();
 
         let mut buffer = Buffer::with_capacity(buffer_length)?;
 
-        buffer.extend_from_slice(hash.to_string())?;
+        buffer.extend_from_slice(Layout::header(encoding.flags(), data.len()))?;
+
+        match encoding {
+            HashEncoding::Text => buffer.extend_from_slice(hash.to_string())?,
+            HashEncoding::Binary => buffer.extend_from_slice(hash_to_bytes(&hash))?,
+        };
+
         buffer.extend_from_slice(data)?;
 
-        let chunk = Self { buffer, hash };
+        let chunk = Self {
+            buffer,
+            hash,
+            offset,
+        };
 
         Ok(chunk)
     }
@@ -57,6 +103,16 @@ impl SerializedDataChunk {
         Self::from_parts_unchecked(data, hash(data)?)
     }
 
+    /// Allocate a `SerializedDataChunk` containing `data`, storing its hash in `encoding`
+    pub fn from_data_with_encoding<D>(data: D, encoding: HashEncoding) -> Result<Self>
+    where
+        D: AsRef<[u8]>,
+    {
+        let data = data.as_ref();
+
+        Self::from_parts_unchecked_with_encoding(data, hash(data)?, encoding)
+    }
+
     /// Returns a reference to this [`SerializedDataChunk`]'s serialized bytes
     #[inline]
     #[must_use]
@@ -64,52 +120,104 @@ impl SerializedDataChunk {
         &self.buffer
     }
 
+    /// Returns the format version of this chunk's serialized bytes.
+    ///
+    /// Chunks in the legacy layout, which has no header, report version `0`.
+    #[must_use]
+    pub fn version(&self) -> u8 {
+        Layout::version_of(&self.buffer)
+    }
+
+    /// Returns how this chunk's serialized bytes store its hash.
+    #[must_use]
+    pub fn hash_encoding(&self) -> HashEncoding {
+        Layout::hash_encoding_of(&self.buffer)
+    }
+
+    /// Validates `bytes` in place, returning a borrowed view without copying.
+    ///
+    /// See [`BorrowedSerializedDataChunk::parse`].
+    pub fn parse(bytes: &[u8]) -> Result<BorrowedSerializedDataChunk<'_>> {
+        BorrowedSerializedDataChunk::parse(bytes)
+    }
+
+    /// Returns a borrowed view of this chunk.
+    #[must_use]
+    pub fn as_borrowed(&self) -> BorrowedSerializedDataChunk<'_> {
+        BorrowedSerializedDataChunk::from_parts_unchecked(&self.buffer, self.hash, self.offset)
+    }
+
     /// Constructs a `SerializedDataChunk` from a serialized buffer.
     ///
     /// `buffer` is validated to be interpretable as a `SerializedDataChunk`,
-    /// and its `hash` is recalculated and verified. However, other things,
-    /// such as padding and buffer length, are not validated.
+    /// and its `hash` is recalculated and verified. Both the current and the
+    /// legacy (version `0`) layout are accepted; the payload length is only
+    /// validated for versioned layouts.
     pub fn from_serialized_buffer(buffer: Buffer) -> Result<Self> {
-        if buffer.len() < HASH_SIZE {
-            return Err(DataChunkError::InvalidLayout);
-        }
-
-        let hash = &buffer[..HASH_SIZE];
-        let data = &buffer[HASH_SIZE..];
-        let calculated_hash = ps_hash::hash(data)?;
-
-        if hash != calculated_hash.to_string().as_bytes() {
-            return Err(DataChunkError::HashMismatch);
-        }
+        let (layout, hash) = Layout::verify(&buffer)?;
 
         let chunk = Self {
             buffer,
-            hash: calculated_hash,
+            hash,
+            offset: layout.offset(),
         };
 
         Ok(chunk)
     }
 
+    /// Reassembles a `SerializedDataChunk` from a buffer previously validated by [`Layout::verify`].
+    pub(crate) const fn from_serialized_parts_unchecked(
+        buffer: Buffer,
+        hash: Hash,
+        offset: usize,
+    ) -> Self {
+        Self {
+            buffer,
+            hash,
+            offset,
+        }
+    }
+
     #[inline]
     /// extracts the serialized `Buffer` from this `SerializedDataChunk`
-    pub fn into_buffer(self) -> Buffer {
-        self.buffer
+    pub fn into_buffer(mut self) -> Buffer {
+        std::mem::take(&mut self.buffer)
     }
 
     #[inline]
     /// extracts the serialized `Buffer` and `Hash` from this `SerializedDataChunk`
-    pub fn into_parts(self) -> (Buffer, Hash) {
-        (self.buffer, self.hash)
+    pub fn into_parts(mut self) -> (Buffer, Hash) {
+        (std::mem::take(&mut self.buffer), self.hash)
     }
 }
 
 impl DataChunk for SerializedDataChunk {
     fn data_ref(&self) -> &[u8] {
-        &self.buffer[HASH_SIZE..]
+        &self.buffer[self.offset..]
     }
 
+    /// Encrypts this chunk in the legacy (version `0`) layout.
+    ///
+    /// The key is derived from the encrypted bytes, so encrypting the header
+    /// would give every chunk a different ciphertext and key than earlier
+    /// versions of this crate did, breaking deduplication against them.
+    /// The versioned layout is only a storage and wire format.
     fn encrypt(&self) -> Result<EncryptedDataChunk> {
-        Ok(ps_cypher::encrypt(&self.buffer)?.into())
+        if self.version() == 0 {
+            return Ok(ps_cypher::encrypt(&self.buffer)?.into());
+        }
+
+        let mut legacy = Buffer::with_capacity(HASH_SIZE + self.data_length())?;
+
+        legacy.extend_from_slice(self.hash.to_string())?;
+        legacy.extend_from_slice(self.data_ref())?;
+
+        let encrypted = ps_cypher::encrypt(&legacy);
+
+        #[cfg(feature = "zeroize")]
+        zeroize::Zeroize::zeroize(&mut legacy[..]);
+
+        Ok(encrypted?.into())
     }
 
     fn hash_ref(&self) -> &Hash {
@@ -117,8 +225,18 @@ impl DataChunk for SerializedDataChunk {
     }
 
     /// Transforms this [`DataChunk`] into [`Bytes`].
+    #[cfg(not(feature = "zeroize"))]
+    fn into_bytes(mut self) -> Bytes {
+        let buffer = std::mem::take(&mut self.buffer);
+
+        Bytes::from_owner(ps_buffer::SharedBuffer::from(buffer)).slice(self.offset..)
+    }
+
+    /// Transforms this [`DataChunk`] into [`Bytes`] owning this chunk,
+    /// so the buffer is still wiped once the last [`Bytes`] is dropped.
+    #[cfg(feature = "zeroize")]
     fn into_bytes(self) -> Bytes {
-        Bytes::from_owner(SharedBuffer::from(self.buffer)).slice(HASH_SIZE..)
+        Bytes::from_owner(self)
     }
 
     /// Transforms this chunk into an [`crate::OwnedDataChunk`]
@@ -129,6 +247,15 @@ impl DataChunk for SerializedDataChunk {
     }
 }
 
+#[cfg(feature = "zeroize")]
+impl Drop for SerializedDataChunk {
+    fn drop(&mut self) {
+        use zeroize::Zeroize;
+
+        self.buffer.zeroize();
+    }
+}
+
 impl AsRef<[u8]> for SerializedDataChunk {
     fn as_ref(&self) -> &[u8] {
         self
@@ -145,8 +272,10 @@ impl Deref for SerializedDataChunk {
 
 #[cfg(test)]
 mod tests {
+    use ps_hash::{HASH_SIZE, HASH_SIZE_BIN};
+
     use super::*;
-    use crate::{DataChunk, OwnedDataChunk};
+    use crate::{DataChunk, DataChunkError, OwnedDataChunk};
 
     #[test]
     fn from_parts_unchecked_accepts_matching_hash() -> Result<()> {
@@ -185,4 +314,141 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn serialized_buffer_roundtrip() -> Result<()> {
+        let chunk = SerializedDataChunk::from_data(b"hello world")?;
+
+        assert_eq!(chunk.version(), FORMAT_VERSION);
+        assert!(chunk.serialized_bytes().starts_with(&MAGIC));
+
+        let parsed = SerializedDataChunk::from_serialized_buffer(Buffer::from_slice(
+            chunk.serialized_bytes(),
+        )?)?;
+
+        assert_eq!(parsed.data_ref(), b"hello world");
+        assert_eq!(parsed.hash(), chunk.hash());
+
+        Ok(())
+    }
+
+    #[test]
+    fn encrypt_uses_legacy_layout() -> Result<()> {
+        let data = b"hello world";
+        let hash = ps_hash::hash(data)?;
+
+        let mut buffer = Buffer::from_slice(hash.to_string())?;
+        buffer.extend_from_slice(data)?;
+
+        let legacy = SerializedDataChunk::from_serialized_buffer(buffer)?.encrypt()?;
+        let text = SerializedDataChunk::from_data(data)?.encrypt()?;
+        let binary =
+            SerializedDataChunk::from_data_with_encoding(data, HashEncoding::Binary)?.encrypt()?;
+
+        assert_eq!(text.hash(), legacy.hash());
+        assert_eq!(text.chunk_key(), legacy.chunk_key());
+        assert_eq!(binary.hash(), legacy.hash());
+        assert_eq!(text.decrypt()?.data_ref(), data);
+
+        Ok(())
+    }
+
+    #[test]
+    fn binary_hash_roundtrip() -> Result<()> {
+        let text = SerializedDataChunk::from_data(b"hello world")?;
+        let binary =
+            SerializedDataChunk::from_data_with_encoding(b"hello world", HashEncoding::Binary)?;
+
+        assert_eq!(binary.hash_encoding(), HashEncoding::Binary);
+        assert_eq!(
+            text.serialized_bytes().len() - binary.serialized_bytes().len(),
+            HASH_SIZE - HASH_SIZE_BIN
+        );
+
+        let parsed = SerializedDataChunk::from_serialized_buffer(Buffer::from_slice(
+            binary.serialized_bytes(),
+        )?)?;
+
+        assert_eq!(parsed.hash_encoding(), HashEncoding::Binary);
+        assert_eq!(parsed.data_ref(), b"hello world");
+        assert_eq!(parsed.hash(), text.hash());
+
+        let mut tampered = Buffer::from_slice(binary.serialized_bytes())?;
+        tampered[HEADER_SIZE] ^= 1;
+
+        assert!(matches!(
+            SerializedDataChunk::from_serialized_buffer(tampered),
+            Err(DataChunkError::CorruptHash)
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn from_serialized_buffer_accepts_legacy_layout() -> Result<()> {
+        let data = b"hello world";
+        let hash = ps_hash::hash(data)?;
+
+        let mut buffer = Buffer::from_slice(hash.to_string())?;
+        buffer.extend_from_slice(data)?;
+
+        let chunk = SerializedDataChunk::from_serialized_buffer(buffer)?;
+
+        assert_eq!(chunk.version(), 0);
+        assert_eq!(chunk.data_ref(), data);
+        assert_eq!(chunk.hash(), hash);
+
+        Ok(())
+    }
+
+    #[test]
+    fn from_serialized_buffer_rejects_bad_headers() -> Result<()> {
+        let chunk = SerializedDataChunk::from_data(b"hello world")?;
+
+        let mut version = Buffer::from_slice(chunk.serialized_bytes())?;
+        version[4] = FORMAT_VERSION + 1;
+
+        assert!(matches!(
+            SerializedDataChunk::from_serialized_buffer(version),
+            Err(DataChunkError::UnsupportedVersion(_))
+        ));
+
+        let mut truncated = Buffer::from_slice(chunk.serialized_bytes())?;
+        truncated.truncate(truncated.len() - 1);
+
+        assert!(matches!(
+            SerializedDataChunk::from_serialized_buffer(truncated),
+            Err(DataChunkError::InvalidLayout)
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn layout_inspects_truncated_headers_without_panicking() -> Result<()> {
+        assert_eq!(Layout::version_of(&MAGIC), 0);
+        assert_eq!(Layout::hash_encoding_of(&MAGIC), HashEncoding::Text);
+        assert_eq!(
+            Layout::version_of(&[MAGIC[0], MAGIC[1], MAGIC[2], MAGIC[3], 1]),
+            1
+        );
+        assert_eq!(
+            Layout::hash_encoding_of(&[MAGIC[0], MAGIC[1], MAGIC[2], MAGIC[3], 1]),
+            HashEncoding::Text
+        );
+
+        let chunk =
+            SerializedDataChunk::from_data_with_encoding(b"hello world", HashEncoding::Binary)?;
+        let layout = Layout::parse(chunk.serialized_bytes())?;
+
+        assert_eq!(layout.version(), FORMAT_VERSION);
+        assert_eq!(layout.flags(), FLAG_BINARY_HASH);
+        assert_eq!(
+            layout.hash_range(),
+            HEADER_SIZE..HEADER_SIZE + HASH_SIZE_BIN
+        );
+        assert_eq!(&chunk.serialized_bytes()[layout.offset()..], b"hello world");
+
+        Ok(())
+    }
 }
@@ -0,0 +1,106 @@
+use bytes::Bytes;
+use ps_buffer::Buffer;
+use serde::{de::Error, Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{BorrowedSerializedDataChunk, SerializedDataChunk};
+
+/// Serializes a [`SerializedDataChunk`] as its serialized bytes.
+impl Serialize for SerializedDataChunk {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_bytes(self.serialized_bytes())
+    }
+}
+
+/// Deserializes a [`SerializedDataChunk`] through [`SerializedDataChunk::from_serialized_buffer`],
+/// which validates its layout and verifies its hash.
+impl<'de> Deserialize<'de> for SerializedDataChunk {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let bytes = Bytes::deserialize(deserializer)?;
+        let buffer = Buffer::from_slice(&bytes).map_err(D::Error::custom)?;
+
+        Self::from_serialized_buffer(buffer).map_err(D::Error::custom)
+    }
+}
+
+/// Serializes a [`BorrowedSerializedDataChunk`] as its serialized bytes,
+/// exactly like the equivalent [`SerializedDataChunk`].
+impl Serialize for BorrowedSerializedDataChunk<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_bytes(self.serialized_bytes())
+    }
+}
+
+/// Deserializes a [`BorrowedSerializedDataChunk`] through [`BorrowedSerializedDataChunk::parse`],
+/// which validates its layout and verifies its hash.
+///
+/// The bytes are borrowed from the input, so this requires a format which
+/// can deserialize `&[u8]` without copying.
+impl<'de> Deserialize<'de> for BorrowedSerializedDataChunk<'de> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let bytes = <&'de [u8]>::deserialize(deserializer)?;
+
+        Self::parse(bytes).map_err(D::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::{
+        de::value::{BorrowedBytesDeserializer, Error},
+        Deserialize,
+    };
+
+    use crate::{BorrowedSerializedDataChunk, DataChunk, Result, SerializedDataChunk};
+
+    #[test]
+    fn serde_roundtrip() -> Result<()> {
+        let chunk = SerializedDataChunk::from_data(b"hello world")?;
+
+        let json = serde_json::to_string(&chunk).map_err(std::io::Error::other)?;
+        let parsed: SerializedDataChunk =
+            serde_json::from_str(&json).map_err(std::io::Error::other)?;
+
+        assert_eq!(parsed.serialized_bytes(), chunk.serialized_bytes());
+        assert_eq!(parsed.hash(), chunk.hash());
+
+        let tampered = json.replacen(']', ",0]", 1);
+
+        assert!(serde_json::from_str::<SerializedDataChunk>(&tampered).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn borrowed_serde_roundtrip() -> Result<()> {
+        let owned = SerializedDataChunk::from_data(b"hello world")?;
+        let borrowed = BorrowedSerializedDataChunk::parse(owned.serialized_bytes())?;
+
+        assert_eq!(
+            serde_json::to_string(&borrowed).map_err(std::io::Error::other)?,
+            serde_json::to_string(&owned).map_err(std::io::Error::other)?
+        );
+
+        let bytes = owned.serialized_bytes();
+        let parsed = BorrowedSerializedDataChunk::deserialize(
+            BorrowedBytesDeserializer::<Error>::new(bytes),
+        )
+        .map_err(std::io::Error::other)?;
+
+        assert_eq!(parsed, borrowed);
+        assert!(bytes.as_ptr_range().contains(&parsed.data_ref().as_ptr()));
+
+        let mut tampered = bytes.to_vec();
+        let last = tampered.len() - 1;
+
+        tampered[last] ^= 1;
+
+        assert!(
+            BorrowedSerializedDataChunk::deserialize(BorrowedBytesDeserializer::<Error>::new(
+                &tampered
+            ))
+            .is_err()
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,291 @@
+use std::{
+    fs::{self, File},
+    io::{ErrorKind, Read, Write},
+    path::{Path, PathBuf},
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+use ps_buffer::Buffer;
+use ps_hash::Hash;
+
+use crate::{ChunkStore, CowDataChunk, DataChunk, DataChunkError, Result, SerializedDataChunk};
+
+/// Distinguishes temporary files written concurrently by the same process.
+static TEMP_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+/// A [`ChunkStore`] persisting serialized chunks in a directory tree.
+///
+/// Chunks are stored as `root/ab/cd/abcd...`, where `abcd...` is the chunk's hash.
+/// Files are written to a temporary name, synced, and renamed into place, so a
+/// crash never leaves a partially written chunk behind. Every read recalculates
+/// and verifies the chunk's hash, and so does every write, which also replaces
+/// a corrupted file already stored under that hash.
+///
+/// [`crate::EncryptedDataChunk`]s are stored by the hash of their ciphertext;
+/// their keys are never written to disk.
+#[derive(Clone, Debug)]
+pub struct FsChunkStore {
+    root: PathBuf,
+}
+
+impl FsChunkStore {
+    /// Opens a store rooted at `root`, creating the directory if necessary.
+    pub fn open(root: impl Into<PathBuf>) -> Result<Self> {
+        let root = root.into();
+
+        fs::create_dir_all(&root)?;
+
+        Ok(Self { root })
+    }
+
+    #[must_use]
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// Returns the path at which the chunk identified by `hash` is stored.
+    #[must_use]
+    pub fn path_of(&self, hash: &Hash) -> PathBuf {
+        let name = hash.to_string();
+
+        self.root.join(&name[0..2]).join(&name[2..4]).join(name)
+    }
+
+    fn write_atomic(directory: &Path, path: &Path, bytes: &[u8]) -> Result<()> {
+        let temp = directory.join(format!(
+            ".{}.{}.tmp",
+            std::process::id(),
+            TEMP_COUNTER.fetch_add(1, Ordering::Relaxed)
+        ));
+
+        let result = File::create(&temp)
+            .and_then(|mut file| {
+                file.write_all(bytes)?;
+                file.sync_all()
+            })
+            .and_then(|()| fs::rename(&temp, path));
+
+        if result.is_err() {
+            let _ = fs::remove_file(&temp);
+        }
+
+        result?;
+
+        Self::sync_directory(directory)
+    }
+
+    /// Persists a rename into `directory`.
+    #[cfg(unix)]
+    fn sync_directory(directory: &Path) -> Result<()> {
+        Ok(File::open(directory)?.sync_all()?)
+    }
+
+    /// Directories cannot be opened for syncing on this platform.
+    #[cfg(not(unix))]
+    #[allow(clippy::unnecessary_wraps)]
+    const fn sync_directory(_: &Path) -> Result<()> {
+        Ok(())
+    }
+
+    /// Returns `true` if `path` holds an intact chunk whose hash is `hash`.
+    fn is_intact(path: &Path, hash: &Hash) -> Result<bool> {
+        let Some(buffer) = Self::read_buffer(path)? else {
+            return Ok(false);
+        };
+
+        Ok(SerializedDataChunk::from_serialized_buffer(buffer)
+            .is_ok_and(|chunk| chunk.hash_ref() == hash))
+    }
+
+    fn read_buffer(path: &Path) -> Result<Option<Buffer>> {
+        let mut file = match File::open(path) {
+            Ok(file) => file,
+            Err(err) if err.kind() == ErrorKind::NotFound => return Ok(None),
+            Err(err) => return Err(err.into()),
+        };
+
+        let length =
+            usize::try_from(file.metadata()?.len()).map_err(|_| DataChunkError::InvalidLayout)?;
+
+        let mut buffer = Buffer::alloc(length)?;
+
+        file.read_exact(&mut buffer)?;
+
+        Ok(Some(buffer))
+    }
+}
+
+impl ChunkStore for FsChunkStore {
+    fn put<C: DataChunk>(&self, chunk: C) -> Result<Hash> {
+        chunk.verify()?;
+
+        let hash = chunk.hash();
+        let path = self.path_of(&hash);
+
+        if Self::is_intact(&path, &hash)? {
+            return Ok(hash);
+        }
+
+        let directory = path.parent().ok_or(DataChunkError::InvalidLayout)?;
+
+        fs::create_dir_all(directory)?;
+
+        Self::write_atomic(directory, &path, chunk.serialize()?.serialized_bytes())?;
+
+        Ok(hash)
+    }
+
+    fn get(&self, hash: &Hash) -> Result<Option<CowDataChunk<'_>>> {
+        let Some(buffer) = Self::read_buffer(&self.path_of(hash))? else {
+            return Ok(None);
+        };
+
+        let chunk = SerializedDataChunk::from_serialized_buffer(buffer)?;
+
+        if chunk.hash_ref() != hash {
+            return Err(DataChunkError::HashMismatch {
+                expected: *hash,
+                actual: chunk.hash(),
+            });
+        }
+
+        Ok(Some(CowDataChunk::Owned(chunk.into_owned())))
+    }
+
+    fn contains(&self, hash: &Hash) -> Result<bool> {
+        Ok(self.path_of(hash).try_exists()?)
+    }
+
+    fn delete(&self, hash: &Hash) -> Result<bool> {
+        match fs::remove_file(self.path_of(hash)) {
+            Ok(()) => Ok(true),
+            Err(err) if err.kind() == ErrorKind::NotFound => Ok(false),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    fn hashes(&self) -> Result<Vec<Hash>> {
+        let mut hashes = Vec::new();
+
+        for outer in fs::read_dir(&self.root)? {
+            let outer = outer?;
+
+            if !outer.file_type()?.is_dir() {
+                continue;
+            }
+
+            for inner in fs::read_dir(outer.path())? {
+                let inner = inner?;
+
+                if !inner.file_type()?.is_dir() {
+                    continue;
+                }
+
+                for entry in fs::read_dir(inner.path())? {
+                    let name = entry?.file_name();
+
+                    // Skips temporary files and anything else not named by a hash.
+                    if let Some(hash) = name.to_str().and_then(|name| Hash::try_from(name).ok()) {
+                        hashes.push(hash);
+                    }
+                }
+            }
+        }
+
+        Ok(hashes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{test_utils::TempPath, OwnedDataChunk};
+
+    #[test]
+    fn roundtrip_plain_and_encrypted() -> Result<()> {
+        let dir = TempPath::new("fs-roundtrip");
+        let store = FsChunkStore::open(&dir.0)?;
+
+        let plain = OwnedDataChunk::from_data(b"hello world".to_vec())?;
+        let encrypted = plain.encrypt()?;
+
+        let plain_hash = store.put(plain.clone())?;
+        let encrypted_hash = store.put(encrypted.borrow())?;
+
+        assert_eq!(encrypted_hash, encrypted.hash());
+        assert_eq!(store.fetch(&plain_hash)?.data_ref(), plain.data_ref());
+
+        let decrypted = encrypted
+            .chunk_key()
+            .decrypt(store.fetch(&encrypted_hash)?)?;
+
+        assert_eq!(decrypted.data_ref(), plain.data_ref());
+
+        let mut hashes = store.hashes()?;
+        hashes.sort();
+
+        let mut expected = vec![plain_hash, encrypted_hash];
+        expected.sort();
+
+        assert_eq!(hashes, expected);
+
+        assert!(store.delete(&plain_hash)?);
+        assert!(!store.contains(&plain_hash)?);
+        assert!(store.get(&plain_hash)?.is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn get_rejects_corrupted_file() -> Result<()> {
+        let dir = TempPath::new("fs-corrupted");
+        let store = FsChunkStore::open(&dir.0)?;
+
+        let hash = store.put(OwnedDataChunk::from_data(b"hello world".to_vec())?)?;
+        let path = store.path_of(&hash);
+
+        let mut bytes = fs::read(&path)?;
+        let last = bytes.len() - 1;
+
+        bytes[last] ^= 1;
+        fs::write(&path, bytes)?;
+
+        assert!(matches!(
+            store.get(&hash),
+            Err(DataChunkError::HashMismatch { .. })
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn put_rejects_and_repairs_bad_chunks() -> Result<()> {
+        let dir = TempPath::new("fs-repair");
+        let store = FsChunkStore::open(&dir.0)?;
+
+        let good = OwnedDataChunk::from_data(b"hello world".to_vec())?;
+        let bad =
+            OwnedDataChunk::from_data_and_hash_unchecked(b"hello there".to_vec(), good.hash());
+
+        assert!(matches!(
+            store.put(bad),
+            Err(DataChunkError::HashMismatch { .. })
+        ));
+        assert!(!store.contains(&good.hash())?);
+
+        let hash = store.put(good.clone())?;
+        let path = store.path_of(&hash);
+
+        let mut bytes = fs::read(&path)?;
+        let last = bytes.len() - 1;
+
+        bytes[last] ^= 1;
+        fs::write(&path, bytes)?;
+
+        store.put(good.clone())?;
+
+        assert_eq!(store.fetch(&hash)?.data_ref(), good.data_ref());
+
+        Ok(())
+    }
+}
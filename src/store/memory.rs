@@ -0,0 +1,135 @@
+use std::{
+    collections::HashMap,
+    sync::{PoisonError, RwLock, RwLockReadGuard, RwLockWriteGuard},
+};
+
+use ps_hash::Hash;
+
+use crate::{ChunkStore, CowDataChunk, DataChunk, OwnedDataChunk, Result};
+
+/// A thread-safe, in-memory [`ChunkStore`].
+#[derive(Debug, Default)]
+pub struct MemoryChunkStore {
+    chunks: RwLock<HashMap<Hash, OwnedDataChunk>>,
+}
+
+impl MemoryChunkStore {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.read().len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.read().is_empty()
+    }
+
+    // Every operation is a single map access, so a poisoned lock cannot
+    // leave the map in an inconsistent state.
+    fn read(&self) -> RwLockReadGuard<'_, HashMap<Hash, OwnedDataChunk>> {
+        self.chunks.read().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn write(&self) -> RwLockWriteGuard<'_, HashMap<Hash, OwnedDataChunk>> {
+        self.chunks.write().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl ChunkStore for MemoryChunkStore {
+    fn put<C: DataChunk>(&self, chunk: C) -> Result<Hash> {
+        chunk.verify()?;
+
+        let chunk = chunk.into_owned();
+        let hash = chunk.hash();
+
+        self.write().insert(hash, chunk);
+
+        Ok(hash)
+    }
+
+    fn get(&self, hash: &Hash) -> Result<Option<CowDataChunk<'_>>> {
+        Ok(self.read().get(hash).cloned().map(CowDataChunk::Owned))
+    }
+
+    fn contains(&self, hash: &Hash) -> Result<bool> {
+        Ok(self.read().contains_key(hash))
+    }
+
+    fn delete(&self, hash: &Hash) -> Result<bool> {
+        Ok(self.write().remove(hash).is_some())
+    }
+
+    fn hashes(&self) -> Result<Vec<Hash>> {
+        Ok(self.read().keys().copied().collect())
+    }
+}
+
+#[allow(clippy::expect_used)]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{BorrowedDataChunk, DataChunkError};
+
+    #[test]
+    fn put_get_delete() -> Result<()> {
+        let store = MemoryChunkStore::new();
+        let data = b"hello world";
+
+        let hash = store.put(BorrowedDataChunk::from_data(data)?)?;
+
+        assert!(store.contains(&hash)?);
+        assert_eq!(store.fetch(&hash)?.data_ref(), data);
+        assert_eq!(store.hashes()?, vec![hash]);
+
+        assert!(store.delete(&hash)?);
+        assert!(!store.delete(&hash)?);
+        assert!(store.get(&hash)?.is_none());
+        assert!(matches!(store.fetch(&hash), Err(DataChunkError::NotFound)));
+
+        Ok(())
+    }
+
+    #[test]
+    fn put_rejects_mismatched_hash() -> Result<()> {
+        let store = MemoryChunkStore::new();
+        let good = OwnedDataChunk::from_data(b"hello world".to_vec())?;
+        let bad =
+            OwnedDataChunk::from_data_and_hash_unchecked(b"hello there".to_vec(), good.hash());
+
+        assert!(matches!(
+            store.put(bad),
+            Err(DataChunkError::HashMismatch { .. })
+        ));
+        assert!(store.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn shared_across_threads() -> Result<()> {
+        let store = MemoryChunkStore::new();
+
+        std::thread::scope(|scope| {
+            let handles: Vec<_> = (0..4_u8)
+                .map(|index| {
+                    let store = &store;
+
+                    scope.spawn(move || store.put(OwnedDataChunk::from_data(vec![index; 16])?))
+                })
+                .collect();
+
+            handles
+                .into_iter()
+                .try_for_each(|handle| handle.join().expect("thread panicked").map(drop))
+        })?;
+
+        assert_eq!(store.len(), 4);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,36 @@
+mod fs;
+mod memory;
+
+use ps_hash::Hash;
+
+use crate::{CowDataChunk, DataChunk, DataChunkError, Result};
+
+pub use fs::*;
+pub use memory::*;
+
+/// A content-addressed collection of chunks, keyed by their [`struct@Hash`].
+pub trait ChunkStore {
+    /// Stores `chunk` under its hash, returning that hash.
+    ///
+    /// Storing a chunk which is already present is not an error. Fails with
+    /// [`DataChunkError::HashMismatch`] if `chunk`'s hash does not match its data,
+    /// so a chunk built with an unchecked hash cannot occupy another chunk's slot.
+    fn put<C: DataChunk>(&self, chunk: C) -> Result<Hash>;
+
+    /// Returns the chunk stored under `hash`, if any.
+    fn get(&self, hash: &Hash) -> Result<Option<CowDataChunk<'_>>>;
+
+    /// Returns `true` if a chunk is stored under `hash`.
+    fn contains(&self, hash: &Hash) -> Result<bool>;
+
+    /// Removes the chunk stored under `hash`, returning whether it was present.
+    fn delete(&self, hash: &Hash) -> Result<bool>;
+
+    /// Returns the hashes of all stored chunks, in no particular order.
+    fn hashes(&self) -> Result<Vec<Hash>>;
+
+    /// Returns the chunk stored under `hash`, or [`DataChunkError::NotFound`].
+    fn fetch(&self, hash: &Hash) -> Result<CowDataChunk<'_>> {
+        self.get(hash)?.ok_or(DataChunkError::NotFound)
+    }
+}
@@ -0,0 +1,215 @@
+use std::io::{self, Write};
+
+use ps_hash::Hash;
+
+use crate::{
+    chunker::cut_point,
+    manifest::{ManifestEntry, MANIFEST_FANOUT},
+    utils, AlignedDataChunk, BorrowedDataChunk, ChunkRef, ChunkerConfig, DataChunk, DataChunkError,
+    EncryptedDataChunk, Manifest, Result, ToDataChunk,
+};
+
+/// Encrypts a stream of arbitrary length into a tree of [`EncryptedDataChunk`]s.
+///
+/// Incoming bytes are split into content-defined chunks, each of which is
+/// encrypted and passed to `sink` as soon as it is complete. The chunks are
+/// indexed by encrypted [`Manifest`]s of at most [`MANIFEST_FANOUT`] entries,
+/// so memory usage is bounded regardless of the input's size.
+///
+/// Call [`Self::finish`] to obtain the [`ChunkRef`] of the root manifest,
+/// from which [`decrypt_stream`] reassembles the original bytes.
+pub struct EncryptingWriter<F>
+where
+    F: FnMut(EncryptedDataChunk) -> Result<()>,
+{
+    sink: F,
+    config: ChunkerConfig,
+    buffer: Vec<u8>,
+    levels: Vec<Manifest>,
+}
+
+impl<F> EncryptingWriter<F>
+where
+    F: FnMut(EncryptedDataChunk) -> Result<()>,
+{
+    pub fn new(sink: F) -> Self {
+        Self::with_config(sink, ChunkerConfig::default())
+    }
+
+    pub const fn with_config(sink: F, config: ChunkerConfig) -> Self {
+        Self {
+            sink,
+            config,
+            buffer: Vec::new(),
+            levels: Vec::new(),
+        }
+    }
+
+    /// Flushes all buffered data and returns a reference to the root manifest.
+    pub fn finish(mut self) -> Result<ChunkRef> {
+        while !self.buffer.is_empty() {
+            self.emit_data()?;
+        }
+
+        let mut level = 0;
+
+        while level + 1 < self.levels.len() {
+            if !self.levels[level].is_empty() {
+                self.flush_level(level)?;
+            }
+
+            level += 1;
+        }
+
+        let root = self.levels.pop().unwrap_or_default();
+        let encrypted = root.to_datachunk()?.encrypt()?;
+        let root = ChunkRef::from(&encrypted);
+
+        (self.sink)(encrypted)?;
+
+        Ok(root)
+    }
+
+    /// Encrypts and emits the first content-defined chunk in the buffer.
+    fn emit_data(&mut self) -> Result<()> {
+        let length = cut_point(&self.buffer, &self.config);
+        let encrypted = BorrowedDataChunk::from_data(&self.buffer[..length])?.encrypt()?;
+        let entry = ManifestEntry::encrypted_with_key(
+            encrypted.hash(),
+            encrypted.chunk_key().clone(),
+            length as u64,
+        );
+
+        (self.sink)(encrypted)?;
+
+        self.buffer.drain(..length);
+        self.push_entry(0, entry)
+    }
+
+    fn push_entry(&mut self, level: usize, entry: ManifestEntry) -> Result<()> {
+        if self.levels.len() == level {
+            let depth = u32::try_from(level).map_err(|_| DataChunkError::InvalidLayout)?;
+
+            self.levels.push(Manifest::with_depth(depth));
+        }
+
+        self.levels[level].push_entry(entry);
+
+        if self.levels[level].len() >= MANIFEST_FANOUT {
+            self.flush_level(level)?;
+        }
+
+        Ok(())
+    }
+
+    /// Encrypts and emits the manifest at `level`, adding it to its parent.
+    fn flush_level(&mut self, level: usize) -> Result<()> {
+        let depth = self.levels[level].depth();
+        let manifest = std::mem::replace(&mut self.levels[level], Manifest::with_depth(depth));
+        let encrypted = manifest.to_datachunk()?.encrypt()?;
+        let entry = ManifestEntry::encrypted_with_key(
+            encrypted.hash(),
+            encrypted.chunk_key().clone(),
+            manifest.total_length()?,
+        );
+
+        (self.sink)(encrypted)?;
+
+        self.push_entry(level + 1, entry)
+    }
+}
+
+impl<F> Write for EncryptingWriter<F>
+where
+    F: FnMut(EncryptedDataChunk) -> Result<()>,
+{
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let max_size = self.config.max_size();
+
+        if self.buffer.len() >= max_size {
+            self.emit_data().map_err(io::Error::other)?;
+        }
+
+        let length = buf.len().min(max_size - self.buffer.len());
+
+        self.buffer.extend_from_slice(&buf[..length]);
+
+        Ok(length)
+    }
+
+    /// Chunk boundaries depend on upcoming data, so buffered bytes are only
+    /// emitted by [`EncryptingWriter::finish`].
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Reassembles a stream produced by [`EncryptingWriter`] into `writer`.
+///
+/// Returns the number of bytes written.
+pub fn decrypt_stream<F, C, W>(root: &ChunkRef, mut lookup: F, writer: &mut W) -> Result<u64>
+where
+    F: FnMut(&Hash) -> Result<C>,
+    C: DataChunk,
+    W: Write,
+{
+    let decrypted = root.resolve(&mut lookup)?;
+    let aligned = utils::AlignedPlaintext::copy_from_slice(decrypted.data_ref());
+
+    AlignedDataChunk::try_bytes_as::<Manifest>(&aligned)?.reassemble(lookup, writer)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+
+    use super::*;
+    use crate::{test_utils::sample, ChunkStore, MemoryChunkStore};
+
+    fn roundtrip(data: &[u8], config: ChunkerConfig) -> Result<(usize, Vec<u8>)> {
+        let store = MemoryChunkStore::new();
+        let mut writer = EncryptingWriter::with_config(|chunk| store.put(chunk).map(drop), config);
+
+        for part in data.chunks(1000) {
+            writer.write_all(part)?;
+        }
+
+        let root = writer.finish()?;
+        let mut output = Vec::new();
+
+        decrypt_stream(&root, |hash| store.fetch(hash), &mut output)?;
+
+        Ok((store.len(), output))
+    }
+
+    #[test]
+    fn roundtrip_single_level() -> Result<()> {
+        let data = sample(200_000);
+        let (_, output) = roundtrip(&data, ChunkerConfig::new(1024, 4096, 16384)?)?;
+
+        assert_eq!(output, data);
+
+        Ok(())
+    }
+
+    #[test]
+    fn roundtrip_nested_manifests() -> Result<()> {
+        let data = sample(400_000);
+        let (stored, output) = roundtrip(&data, ChunkerConfig::new(64, 128, 256)?)?;
+
+        assert!(stored > MANIFEST_FANOUT + 2);
+        assert_eq!(output, data);
+
+        Ok(())
+    }
+
+    #[test]
+    fn roundtrip_empty() -> Result<()> {
+        let (stored, output) = roundtrip(&[], ChunkerConfig::default())?;
+
+        assert_eq!(stored, 1);
+        assert!(output.is_empty());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,37 @@
+//! Helpers shared by the unit tests.
+
+use std::{fs, path::PathBuf};
+
+/// A path in the temporary directory, removed along with its contents when dropped.
+pub struct TempPath(pub PathBuf);
+
+impl TempPath {
+    /// Returns a path unique to `name` and the current process, without creating it.
+    pub fn new(name: &str) -> Self {
+        Self(std::env::temp_dir().join(format!("ps-datachunk-{name}-{}", std::process::id())))
+    }
+}
+
+impl Drop for TempPath {
+    fn drop(&mut self) {
+        let _ = if self.0.is_dir() {
+            fs::remove_dir_all(&self.0)
+        } else {
+            fs::remove_file(&self.0)
+        };
+    }
+}
+
+/// Returns `length` pseudo-random bytes, the same on every call.
+pub fn sample(length: usize) -> Vec<u8> {
+    let mut state = 0x1234_5678_u32;
+
+    (0..length)
+        .map(|_| {
+            state ^= state << 13;
+            state ^= state >> 17;
+            state ^= state << 5;
+            state.to_le_bytes()[0]
+        })
+        .collect()
+}
@@ -0,0 +1,256 @@
+use std::{marker::PhantomData, ops::Deref};
+
+use bytes::Bytes;
+use rancor::{Error, Strategy};
+use rkyv::{
+    api::high::{HighSerializer, HighValidator},
+    bytecheck::CheckBytes,
+    de::Pool,
+    ser::allocator::ArenaHandle,
+    util::AlignedVec,
+    Archive, Deserialize, Serialize,
+};
+
+use crate::{AlignedDataChunk, DataChunk, DataChunkError, Hash, OwnedDataChunk, Result};
+
+/// Magic bytes identifying a fingerprinted typed chunk.
+pub const FINGERPRINT_MAGIC: [u8; 4] = [0x89, b'P', b'D', b'T'];
+
+/// The envelope format version written by [`FingerprintedDataChunk::from_value`].
+pub const FINGERPRINT_VERSION: u8 = 1;
+
+/// Size of the envelope header preceding the archived value.
+///
+/// A multiple of rkyv's alignment, so the archived value stays aligned.
+pub const FINGERPRINT_HEADER_SIZE: usize = 16;
+
+/// A stable identifier of a type's archived layout.
+///
+/// Implement by hashing a name which changes whenever the archived layout does,
+/// such as `fingerprint("my_app::Record/v2")`.
+pub trait TypeFingerprint {
+    const FINGERPRINT: u64;
+}
+
+/// Calculates the 64-bit FNV-1a hash of `name`, for use as a [`TypeFingerprint`].
+#[must_use]
+pub const fn fingerprint(name: &str) -> u64 {
+    let bytes = name.as_bytes();
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    let mut index = 0;
+
+    while index < bytes.len() {
+        hash ^= bytes[index] as u64;
+        hash = hash.wrapping_mul(0x0100_0000_01b3);
+        index += 1;
+    }
+
+    hash
+}
+
+macro_rules! impl_type_fingerprint {
+    ($($ty:ty),*) => {
+        $(
+            impl TypeFingerprint for $ty {
+                const FINGERPRINT: u64 = fingerprint(stringify!($ty));
+            }
+        )*
+    };
+}
+
+impl_type_fingerprint!(bool, u8, u16, u32, u64, u128, i8, i16, i32, i64, i128, f32, f64, String);
+
+/// Reads the fingerprint from a fingerprinted chunk's header, without validating its payload.
+///
+/// Useful for dispatching on the record type in stores holding many types.
+pub fn fingerprint_of(bytes: &[u8]) -> Result<u64> {
+    let header = bytes
+        .get(..FINGERPRINT_HEADER_SIZE)
+        .ok_or(DataChunkError::InvalidLayout)?;
+
+    if header[..4] != FINGERPRINT_MAGIC {
+        return Err(DataChunkError::InvalidLayout);
+    }
+
+    if header[4] != FINGERPRINT_VERSION {
+        return Err(DataChunkError::UnsupportedVersion(header[4]));
+    }
+
+    Ok(u64::from_le_bytes(header[8..16].try_into()?))
+}
+
+/// A typed chunk whose bytes start with a header carrying `T`'s [`TypeFingerprint`].
+///
+/// Unlike [`crate::TypedDataChunk`], loading checks the fingerprint, so bytes archived
+/// from a different type are rejected with [`DataChunkError::TypeMismatch`] even if
+/// they happen to validate as `T::Archived`.
+pub struct FingerprintedDataChunk<D: DataChunk, T: Archive> {
+    chunk: D,
+    _p: PhantomData<T::Archived>,
+}
+
+impl<D, T> FingerprintedDataChunk<D, T>
+where
+    D: DataChunk,
+    T: Archive + TypeFingerprint,
+    T::Archived: for<'a> CheckBytes<HighValidator<'a, Error>>,
+{
+    /// Checks the header and fingerprint, then validates the archived value.
+    pub fn from_data_chunk(chunk: D) -> Result<Self> {
+        let actual = fingerprint_of(chunk.data_ref())?;
+
+        if actual != T::FINGERPRINT {
+            return Err(DataChunkError::TypeMismatch {
+                expected: T::FINGERPRINT,
+                actual,
+            });
+        }
+
+        rkyv::access::<T::Archived, Error>(&chunk.data_ref()[FINGERPRINT_HEADER_SIZE..])
+            .map_err(|_| DataChunkError::InvalidArchive)?;
+
+        Ok(Self {
+            chunk,
+            _p: PhantomData,
+        })
+    }
+
+    /// Returns the underlying chunk, including the header.
+    pub fn into_inner(self) -> D {
+        self.chunk
+    }
+
+    /// Deserializes the archived value into an owned `T`.
+    pub fn to_value(&self) -> Result<T>
+    where
+        T::Archived: Deserialize<T, Strategy<Pool, Error>>,
+    {
+        rkyv::deserialize::<T, Error>(&**self).map_err(DataChunkError::Deserialization)
+    }
+}
+
+impl<T> FingerprintedDataChunk<AlignedDataChunk, T>
+where
+    T: Archive
+        + TypeFingerprint
+        + for<'a> Serialize<HighSerializer<AlignedVec, ArenaHandle<'a>, Error>>,
+    T::Archived: for<'a> CheckBytes<HighValidator<'a, Error>>,
+{
+    /// Archives `value` behind a header carrying `T`'s fingerprint.
+    pub fn from_value(value: &T) -> Result<Self> {
+        let archive = rkyv::to_bytes::<Error>(value).map_err(|_| DataChunkError::Serialization)?;
+
+        let mut data: AlignedVec =
+            AlignedVec::with_capacity(FINGERPRINT_HEADER_SIZE + archive.len());
+
+        data.extend_from_slice(&FINGERPRINT_MAGIC);
+        data.extend_from_slice(&[FINGERPRINT_VERSION, 0, 0, 0]);
+        data.extend_from_slice(&T::FINGERPRINT.to_le_bytes());
+        data.extend_from_slice(&archive);
+
+        Self::from_data_chunk(AlignedDataChunk::from_data_vec(data)?)
+    }
+}
+
+impl<D, T> Deref for FingerprintedDataChunk<D, T>
+where
+    D: DataChunk,
+    T: Archive,
+    T::Archived: for<'a> CheckBytes<HighValidator<'a, Error>>,
+{
+    type Target = T::Archived;
+
+    fn deref(&self) -> &Self::Target {
+        // SAFETY: `from_data_chunk` validated the payload as a `T::Archived`,
+        // and the `DataChunk` contract keeps it stable for `&self`.
+        unsafe {
+            rkyv::access_unchecked::<T::Archived>(&self.chunk.data_ref()[FINGERPRINT_HEADER_SIZE..])
+        }
+    }
+}
+
+impl<D, T> DataChunk for FingerprintedDataChunk<D, T>
+where
+    D: DataChunk,
+    T: Archive,
+{
+    fn data_ref(&self) -> &[u8] {
+        self.chunk.data_ref()
+    }
+
+    fn hash_ref(&self) -> &Hash {
+        self.chunk.hash_ref()
+    }
+
+    /// Transforms this [`DataChunk`] into [`Bytes`].
+    fn into_bytes(self) -> Bytes {
+        self.chunk.into_bytes()
+    }
+
+    /// Transforms this chunk into an [`OwnedDataChunk`]
+    fn into_owned(self) -> OwnedDataChunk {
+        self.chunk.into_owned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Archive, Serialize, Deserialize, Debug, PartialEq)]
+    struct Record {
+        count: u32,
+    }
+
+    impl TypeFingerprint for Record {
+        const FINGERPRINT: u64 = fingerprint("ps_datachunk::tests::Record/v1");
+    }
+
+    #[test]
+    fn fingerprint_is_fnv1a() {
+        assert_eq!(fingerprint(""), 0xcbf2_9ce4_8422_2325);
+        assert_eq!(fingerprint("a"), 0xaf63_dc4c_8601_ec8c);
+        assert_ne!(u32::FINGERPRINT, i32::FINGERPRINT);
+    }
+
+    #[test]
+    fn fingerprinted_roundtrip() -> Result<()> {
+        let chunk = FingerprintedDataChunk::from_value(&Record { count: 7 })?;
+
+        assert_eq!(chunk.count, 7);
+        assert_eq!(chunk.to_value()?, Record { count: 7 });
+        assert_eq!(fingerprint_of(chunk.data_ref())?, Record::FINGERPRINT);
+
+        let reloaded = FingerprintedDataChunk::<_, Record>::from_data_chunk(chunk.into_inner())?;
+
+        assert_eq!(reloaded.count, 7);
+
+        Ok(())
+    }
+
+    #[test]
+    fn rejects_other_types() -> Result<()> {
+        let chunk = FingerprintedDataChunk::from_value(&7_u32)?.into_inner();
+
+        // Without the fingerprint, an archived `u32` would be accepted as a `Record`.
+        assert!(rkyv::access::<ArchivedRecord, Error>(
+            &chunk.data_ref()[FINGERPRINT_HEADER_SIZE..]
+        )
+        .is_ok());
+
+        assert!(matches!(
+            FingerprintedDataChunk::<_, Record>::from_data_chunk(chunk),
+            Err(DataChunkError::TypeMismatch { expected, actual })
+                if expected == Record::FINGERPRINT && actual == u32::FINGERPRINT
+        ));
+
+        let plain = crate::ToDataChunk::to_datachunk(&Record { count: 7 })?;
+
+        assert!(matches!(
+            FingerprintedDataChunk::<_, Record>::from_data_chunk(plain),
+            Err(DataChunkError::InvalidLayout)
+        ));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,149 @@
+use std::ops::Deref;
+
+use rancor::Error;
+use rkyv::{api::high::HighValidator, bytecheck::CheckBytes, seal::Seal, Archive};
+
+use crate::{AlignedDataChunk, Hash, Result, TypedDataChunk};
+
+/// Mutable access to the archived value of a [`TypedDataChunk`], obtained through
+/// [`TypedDataChunk::edit`].
+///
+/// Only fields which can be modified in place, such as integers, are writable through
+/// [`Seal`]. The chunk's hash is recalculated by [`Self::commit`] or, failing that,
+/// when the guard is dropped.
+///
+/// Leaking the guard, e.g. with [`std::mem::forget`], skips the recalculation and
+/// leaves the chunk with a stale hash, which [`crate::DataChunk::verify`] reports.
+pub struct TypedMutGuard<'lt, T: Archive> {
+    typed: &'lt mut TypedDataChunk<AlignedDataChunk, T>,
+    dirty: bool,
+}
+
+impl<T> TypedDataChunk<AlignedDataChunk, T>
+where
+    T: Archive,
+    T::Archived: for<'a> CheckBytes<HighValidator<'a, Error>>,
+{
+    /// Returns a guard for modifying the archived value in place.
+    pub const fn edit(&mut self) -> TypedMutGuard<'_, T> {
+        TypedMutGuard {
+            typed: self,
+            dirty: false,
+        }
+    }
+}
+
+impl<T> TypedMutGuard<'_, T>
+where
+    T: Archive,
+    T::Archived: for<'a> CheckBytes<HighValidator<'a, Error>>,
+{
+    /// Returns sealed mutable access to the archived value.
+    pub fn archived(&mut self) -> Seal<'_, T::Archived> {
+        self.dirty = true;
+
+        // SAFETY:
+        // - `TypedDataChunk::from_data_chunk` validated the bytes as a `T::Archived`.
+        // - `Seal` only permits modifications which keep the archive valid.
+        unsafe { rkyv::access_unchecked_mut::<T::Archived>(self.typed.chunk.data_mut()) }
+    }
+
+    /// Recalculates the chunk's hash, returning it.
+    pub fn commit(mut self) -> Result<Hash> {
+        self.dirty = false;
+
+        self.typed.chunk.rehash()
+    }
+}
+
+impl<T> Deref for TypedMutGuard<'_, T>
+where
+    T: Archive,
+    T::Archived: for<'a> CheckBytes<HighValidator<'a, Error>>,
+{
+    type Target = T::Archived;
+
+    fn deref(&self) -> &Self::Target {
+        self.typed
+    }
+}
+
+impl<T: Archive> Drop for TypedMutGuard<'_, T> {
+    /// Recalculates the chunk's hash if it was modified and not committed.
+    ///
+    /// Errors cannot be reported from `drop`, so a failure leaves the stale hash
+    /// in place; use [`TypedMutGuard::commit`] to handle the error.
+    fn drop(&mut self) {
+        if self.dirty {
+            let _ = self.typed.chunk.rehash();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rkyv::{munge::munge, Archive, Deserialize, Serialize};
+
+    use crate::{DataChunk, Result, ToTypedDataChunk};
+
+    #[derive(Archive, Serialize, Deserialize)]
+    struct Counter {
+        name: String,
+        count: u32,
+    }
+
+    #[test]
+    fn edit_rehashes_on_commit_and_drop() -> Result<()> {
+        let mut typed = Counter {
+            name: "hits".to_string(),
+            count: 1,
+        }
+        .to_typed_datachunk()?;
+
+        let original = typed.hash();
+
+        let mut guard = typed.edit();
+        let archived = guard.archived();
+        munge!(let ArchivedCounter { mut count, .. } = archived);
+        *count = 2.into();
+        let committed = guard.commit()?;
+
+        assert_eq!(typed.count, 2);
+        assert_eq!(typed.name, "hits");
+        assert_eq!(committed, typed.hash());
+        assert_ne!(committed, original);
+        typed.verify()?;
+
+        {
+            let mut guard = typed.edit();
+            let archived = guard.archived();
+            munge!(let ArchivedCounter { mut count, .. } = archived);
+            *count = 1.into();
+        }
+
+        assert_eq!(typed.hash(), original);
+        typed.verify()?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn forgotten_guard_leaves_stale_hash() -> Result<()> {
+        let mut typed = Counter {
+            name: "hits".to_string(),
+            count: 1,
+        }
+        .to_typed_datachunk()?;
+
+        let mut guard = typed.edit();
+        let archived = guard.archived();
+        munge!(let ArchivedCounter { mut count, .. } = archived);
+        *count = 2.into();
+        std::mem::forget(guard);
+
+        assert_eq!(typed.count, 2);
+        assert!(typed.verify().is_err());
+
+        Ok(())
+    }
+}
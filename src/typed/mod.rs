@@ -1,3 +1,5 @@
+mod fingerprint;
+mod guard;
 mod implementations;
 
 use std::{marker::PhantomData, ops::Deref};
@@ -7,13 +9,17 @@ use rancor::{Error, Strategy};
 use rkyv::{
     api::high::HighValidator,
     bytecheck::CheckBytes,
+    de::Pool,
     ser::{allocator::ArenaHandle, sharing::Share, Serializer},
     util::AlignedVec,
-    Archive, Serialize,
+    Archive, Deserialize, Serialize,
 };
 
 use crate::{AlignedDataChunk, DataChunk, Hash, Result};
 
+pub use fingerprint::*;
+pub use guard::TypedMutGuard;
+
 pub struct TypedDataChunk<D: DataChunk, T: rkyv::Archive> {
     chunk: D,
     _p: PhantomData<T::Archived>,
@@ -60,6 +66,34 @@ where
     }
 }
 
+impl<D, T> TypedDataChunk<D, T>
+where
+    D: DataChunk,
+    T: Archive,
+    T::Archived:
+        for<'a> CheckBytes<HighValidator<'a, Error>> + Deserialize<T, Strategy<Pool, Error>>,
+{
+    /// Deserializes the archived value into an owned `T`.
+    pub fn to_value(&self) -> Result<T> {
+        rkyv::deserialize::<T, Error>(&**self).map_err(crate::DataChunkError::Deserialization)
+    }
+
+    /// Deserializes the value, applies `f` to it, and archives the result into a new chunk.
+    ///
+    /// `self` is left unchanged; the returned chunk has its own hash.
+    pub fn update<F>(&self, f: F) -> Result<TypedDataChunk<AlignedDataChunk, T>>
+    where
+        F: FnOnce(&mut T),
+        T: for<'a> Serialize<Strategy<Serializer<AlignedVec, ArenaHandle<'a>, Share>, Error>>,
+    {
+        let mut value = self.to_value()?;
+
+        f(&mut value);
+
+        value.to_typed_datachunk()
+    }
+}
+
 impl<D, T> Deref for TypedDataChunk<D, T>
 where
     D: DataChunk,
@@ -148,6 +182,37 @@ mod tests {
         Ok(())
     }
 
+    #[derive(Archive, Serialize, Deserialize, Debug, PartialEq)]
+    struct Record {
+        name: String,
+        count: u32,
+    }
+
+    #[test]
+    fn to_value_and_update() -> Result<()> {
+        let record = Record {
+            name: "hello".to_string(),
+            count: 1,
+        };
+
+        let typed = record.to_typed_datachunk()?;
+
+        assert_eq!(typed.to_value()?, record);
+
+        let updated = typed.update(|record| {
+            record.name.push_str(" world");
+            record.count += 1;
+        })?;
+
+        assert_eq!(updated.name, "hello world");
+        assert_eq!(updated.count, 2);
+        assert_ne!(updated.hash(), typed.hash());
+        assert_eq!(updated.hash(), ps_hash::hash(updated.data_ref())?);
+        assert_eq!(typed.to_value()?, record);
+
+        Ok(())
+    }
+
     #[test]
     fn from_data_chunk_rejects_invalid_archive() {
         let chunk = OwnedDataChunk::from_data([1_u8, 2, 3]).expect("hashing failed");
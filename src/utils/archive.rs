@@ -0,0 +1,66 @@
+use ps_hash::{Hash, HASH_SIZE_BIN};
+use rancor::{Fallible, Source};
+use rkyv::{
+    with::{ArchiveWith, DeserializeWith, SerializeWith},
+    Archive, Place, Serialize,
+};
+
+use super::hash_to_bytes;
+use crate::ChunkKey;
+
+/// Archives a [`struct@Hash`] as its raw binary representation.
+///
+/// Use as `#[rkyv(with = AsHashBytes)]` on a field of type [`struct@Hash`] or [`ChunkKey`].
+/// Deserialization validates the archived bytes.
+#[derive(Clone, Copy, Debug)]
+pub struct AsHashBytes;
+
+impl ArchiveWith<Hash> for AsHashBytes {
+    type Archived = [u8; HASH_SIZE_BIN];
+    type Resolver = [(); HASH_SIZE_BIN];
+
+    fn resolve_with(field: &Hash, resolver: Self::Resolver, out: Place<Self::Archived>) {
+        hash_to_bytes(field).resolve(resolver, out);
+    }
+}
+
+impl<S: Fallible + ?Sized> SerializeWith<Hash, S> for AsHashBytes {
+    fn serialize_with(field: &Hash, serializer: &mut S) -> Result<Self::Resolver, S::Error> {
+        hash_to_bytes(field).serialize(serializer)
+    }
+}
+
+impl<D> DeserializeWith<[u8; HASH_SIZE_BIN], Hash, D> for AsHashBytes
+where
+    D: Fallible + ?Sized,
+    D::Error: Source,
+{
+    fn deserialize_with(field: &[u8; HASH_SIZE_BIN], _: &mut D) -> Result<Hash, D::Error> {
+        Hash::validate(field).map_err(D::Error::new)
+    }
+}
+
+impl ArchiveWith<ChunkKey> for AsHashBytes {
+    type Archived = [u8; HASH_SIZE_BIN];
+    type Resolver = [(); HASH_SIZE_BIN];
+
+    fn resolve_with(field: &ChunkKey, resolver: Self::Resolver, out: Place<Self::Archived>) {
+        field.to_bytes().resolve(resolver, out);
+    }
+}
+
+impl<S: Fallible + ?Sized> SerializeWith<ChunkKey, S> for AsHashBytes {
+    fn serialize_with(field: &ChunkKey, serializer: &mut S) -> Result<Self::Resolver, S::Error> {
+        field.to_bytes().serialize(serializer)
+    }
+}
+
+impl<D> DeserializeWith<[u8; HASH_SIZE_BIN], ChunkKey, D> for AsHashBytes
+where
+    D: Fallible + ?Sized,
+    D::Error: Source,
+{
+    fn deserialize_with(field: &[u8; HASH_SIZE_BIN], _: &mut D) -> Result<ChunkKey, D::Error> {
+        ChunkKey::from_bytes(field).map_err(D::Error::new)
+    }
+}
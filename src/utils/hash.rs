@@ -0,0 +1,75 @@
+use ps_hash::{Hash, DIGEST_SIZE, HASH_SIZE_BIN, PARITY_OFFSET};
+
+use crate::{DataChunkError, Result};
+
+/// Returns the raw binary representation of `hash`.
+///
+/// The result can be turned back into a [`struct@Hash`] using [`Hash::validate`].
+#[must_use]
+pub fn hash_to_bytes(hash: &Hash) -> [u8; HASH_SIZE_BIN] {
+    let mut bytes = [0u8; HASH_SIZE_BIN];
+
+    bytes[..DIGEST_SIZE].copy_from_slice(hash.digest());
+    bytes[DIGEST_SIZE..PARITY_OFFSET].copy_from_slice(&hash.data_max_len().to_16_bits());
+    bytes[PARITY_OFFSET..].copy_from_slice(hash.parity());
+
+    bytes
+}
+
+/// Recalculates the hash of `data` and checks that it equals `expected`.
+///
+/// Fails with [`DataChunkError::HashMismatch`] otherwise.
+pub fn verify_hash(data: &[u8], expected: &Hash) -> Result<()> {
+    let actual = ps_hash::hash(data)?;
+
+    if actual != *expected {
+        return Err(DataChunkError::HashMismatch {
+            expected: *expected,
+            actual,
+        });
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use ps_hash::Hash;
+
+    use super::{hash_to_bytes, verify_hash};
+    use crate::{DataChunkError, Result};
+
+    #[test]
+    fn binary_hash_roundtrip() -> Result<()> {
+        let hash = ps_hash::hash(b"hello world")?;
+
+        assert_eq!(Hash::validate(hash_to_bytes(&hash))?, hash);
+        assert_eq!(
+            &hash_to_bytes(&hash)[..hash.compact().len()],
+            hash.compact()
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn verify_hash_reports_both_hashes() -> Result<()> {
+        let expected = ps_hash::hash(b"hello world")?;
+        let actual = ps_hash::hash(b"hello there")?;
+
+        verify_hash(b"hello world", &expected)?;
+
+        let Err(DataChunkError::HashMismatch {
+            expected: reported_expected,
+            actual: reported_actual,
+        }) = verify_hash(b"hello there", &expected)
+        else {
+            panic!("expected a hash mismatch");
+        };
+
+        assert_eq!(reported_expected, expected);
+        assert_eq!(reported_actual, actual);
+
+        Ok(())
+    }
+}
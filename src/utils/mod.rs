@@ -1,7 +1,17 @@
+mod archive;
 mod constants;
 mod decrypt;
+mod hash;
+mod plaintext;
 mod rounding;
+#[cfg(feature = "serde")]
+pub(crate) mod serde_chunk;
+#[cfg(feature = "serde")]
+pub mod serde_hash;
 
+pub use archive::*;
 pub use constants::*;
 pub use decrypt::*;
+pub use hash::*;
+pub(crate) use plaintext::*;
 pub use rounding::*;
@@ -0,0 +1,36 @@
+use std::ops::Deref;
+
+use rkyv::util::AlignedVec;
+
+/// An aligned copy of decrypted bytes.
+///
+/// With the `zeroize` feature, the copy is overwritten with zeroes when dropped.
+pub struct AlignedPlaintext(AlignedVec);
+
+impl AlignedPlaintext {
+    /// Copies `data` into a buffer allocated to fit it, so no stale copy is left behind by growing.
+    pub fn copy_from_slice(data: &[u8]) -> Self {
+        let mut aligned = AlignedVec::with_capacity(data.len());
+
+        aligned.extend_from_slice(data);
+
+        Self(aligned)
+    }
+}
+
+impl Deref for AlignedPlaintext {
+    type Target = [u8];
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl Drop for AlignedPlaintext {
+    fn drop(&mut self) {
+        use zeroize::Zeroize;
+
+        self.0.as_mut_slice().zeroize();
+    }
+}
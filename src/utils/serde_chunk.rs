@@ -0,0 +1,48 @@
+use bytes::Bytes;
+use ps_hash::Hash;
+use serde::{de::Error, Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{utils, DataChunk};
+
+/// Borrowed bytes, serialized with [`Serializer::serialize_bytes`].
+pub struct RawBytes<'lt>(pub &'lt [u8]);
+
+impl Serialize for RawBytes<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_bytes(self.0)
+    }
+}
+
+#[derive(Serialize)]
+#[serde(rename = "DataChunk")]
+struct SerializeChunk<'lt> {
+    #[serde(with = "utils::serde_hash")]
+    hash: Hash,
+    data: RawBytes<'lt>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename = "DataChunk")]
+struct DeserializeChunk {
+    #[serde(with = "utils::serde_hash")]
+    hash: Hash,
+    data: Bytes,
+}
+
+/// Serializes `chunk` as a `DataChunk { hash, data }` struct.
+pub fn serialize<C: DataChunk, S: Serializer>(chunk: &C, serializer: S) -> Result<S::Ok, S::Error> {
+    SerializeChunk {
+        hash: chunk.hash(),
+        data: RawBytes(chunk.data_ref()),
+    }
+    .serialize(serializer)
+}
+
+/// Deserializes a `DataChunk { hash, data }` struct, verifying its hash.
+pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<(Bytes, Hash), D::Error> {
+    let DeserializeChunk { hash, data } = DeserializeChunk::deserialize(deserializer)?;
+
+    utils::verify_hash(&data, &hash).map_err(D::Error::custom)?;
+
+    Ok((data, hash))
+}
@@ -0,0 +1,30 @@
+//! Serializes a [`struct@Hash`] as its textual representation.
+//!
+//! Use with `#[serde(with = "ps_datachunk::utils::serde_hash")]`.
+
+use std::fmt::Formatter;
+
+use ps_hash::Hash;
+use serde::{de::Visitor, Deserializer, Serializer};
+
+pub fn serialize<S: Serializer>(hash: &Hash, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.collect_str(hash)
+}
+
+pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Hash, D::Error> {
+    struct HashVisitor;
+
+    impl Visitor<'_> for HashVisitor {
+        type Value = Hash;
+
+        fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+            formatter.write_str("a hash")
+        }
+
+        fn visit_str<E: serde::de::Error>(self, value: &str) -> Result<Self::Value, E> {
+            Hash::try_from(value).map_err(E::custom)
+        }
+    }
+
+    deserializer.deserialize_str(HashVisitor)
+}
@@ -0,0 +1,109 @@
+use std::ops::Deref;
+
+use bytes::Bytes;
+use ps_hash::Hash;
+
+use crate::{DataChunk, EncryptedDataChunk, OwnedDataChunk, Result, SerializedDataChunk};
+
+/// A [`DataChunk`] whose hash has been checked against its data.
+///
+/// The only way to obtain a `Verified<D>` is through [`Verified::new`],
+/// so holding one proves that [`DataChunk::verify`] succeeded.
+#[derive(Clone, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Verified<D: DataChunk>(D);
+
+impl<D: DataChunk> Verified<D> {
+    /// Verifies `chunk`'s hash, failing with [`crate::DataChunkError::HashMismatch`] if it is incorrect.
+    pub fn new(chunk: D) -> Result<Self> {
+        chunk.verify()?;
+
+        Ok(Self(chunk))
+    }
+
+    #[must_use]
+    pub const fn get_ref(&self) -> &D {
+        &self.0
+    }
+
+    pub fn into_inner(self) -> D {
+        self.0
+    }
+}
+
+impl<D: DataChunk> DataChunk for Verified<D> {
+    fn data_ref(&self) -> &[u8] {
+        self.0.data_ref()
+    }
+
+    fn hash_ref(&self) -> &Hash {
+        self.0.hash_ref()
+    }
+
+    fn verify(&self) -> Result<()> {
+        Ok(())
+    }
+
+    fn encrypt(&self) -> Result<EncryptedDataChunk> {
+        self.0.encrypt()
+    }
+
+    fn decrypt(&self, key: &Hash) -> Result<SerializedDataChunk> {
+        self.0.decrypt(key)
+    }
+
+    fn serialize(&self) -> Result<SerializedDataChunk> {
+        self.0.serialize()
+    }
+
+    fn into_bytes(self) -> Bytes {
+        self.0.into_bytes()
+    }
+
+    fn into_owned(self) -> OwnedDataChunk {
+        self.0.into_owned()
+    }
+}
+
+impl<D: DataChunk> Deref for Verified<D> {
+    type Target = D;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<D: DataChunk> AsRef<[u8]> for Verified<D> {
+    fn as_ref(&self) -> &[u8] {
+        self.0.data_ref()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{BorrowedDataChunk, DataChunkError};
+
+    #[test]
+    fn verified_accepts_correct_hash() -> Result<()> {
+        let chunk = Verified::new(BorrowedDataChunk::from_data(b"hello world")?)?;
+
+        assert_eq!(chunk.data_ref(), b"hello world");
+        assert_eq!(chunk.hash(), ps_hash::hash(b"hello world")?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn verified_rejects_incorrect_hash() -> Result<()> {
+        let expected = ps_hash::hash(b"other bytes")?;
+        let chunk = OwnedDataChunk::from_data_and_hash_unchecked(b"hello world".to_vec(), expected);
+
+        assert!(matches!(
+            chunk.verify(),
+            Err(DataChunkError::HashMismatch { expected: e, .. }) if e == expected
+        ));
+        assert!(Verified::new(chunk).is_err());
+
+        Ok(())
+    }
+}